@@ -0,0 +1,71 @@
+//! Parse and solve time of every day with its real input.
+//!
+//! Save a baseline with `cargo bench -- --save-baseline <name>` and compare
+//! later runs against it with `cargo bench -- --baseline <name>`, a single
+//! day can be selected with a filter, e.g. `cargo bench -- day11/`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_2020::day1::Day1;
+use aoc_2020::day10::Day10;
+use aoc_2020::day11::Day11;
+use aoc_2020::day12::Day12;
+use aoc_2020::day13::Day13;
+use aoc_2020::day14::Day14;
+use aoc_2020::day15::Day15;
+use aoc_2020::day16::Day16;
+use aoc_2020::day2::Day2;
+use aoc_2020::day3::Day3;
+use aoc_2020::day4::Day4;
+use aoc_2020::day5::Day5;
+use aoc_2020::day6::Day6;
+use aoc_2020::day7::Day7;
+use aoc_2020::day8::Day8;
+use aoc_2020::day9::Day9;
+use aoc_2020::{read_input, Solution};
+
+/// Benchmark the parsing stage and both parts of `S` separately using the
+/// real input of `day`.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u32, input: &str) {
+    let parsed = S::parse(input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{}", day));
+    if day == 15 {
+        // Part 2 plays 30M turns per iteration, use the minimum number of
+        // samples so a full run stays reasonable.
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+/// Same as `bench_day` reading the input from `files/dayN/input.txt`.
+fn bench_file<S: Solution>(c: &mut Criterion, day: u32) {
+    let input = read_input(format!("files/day{}/input.txt", day)).unwrap();
+    bench_day::<S>(c, day, &input);
+}
+
+fn days(c: &mut Criterion) {
+    bench_file::<Day1>(c, 1);
+    bench_file::<Day2>(c, 2);
+    bench_file::<Day3>(c, 3);
+    bench_file::<Day4>(c, 4);
+    bench_file::<Day5>(c, 5);
+    bench_file::<Day6>(c, 6);
+    bench_file::<Day7>(c, 7);
+    bench_file::<Day8>(c, 8);
+    bench_file::<Day9>(c, 9);
+    bench_file::<Day10>(c, 10);
+    bench_file::<Day11>(c, 11);
+    bench_file::<Day12>(c, 12);
+    bench_file::<Day13>(c, 13);
+    bench_file::<Day14>(c, 14);
+    bench_file::<Day15>(c, 15);
+    bench_file::<Day16>(c, 16);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,83 @@
+use std::env;
+use std::process;
+
+use anyhow::{anyhow, bail, Result as AnyResult};
+use aoc_2020::day1::Day1;
+use aoc_2020::day10::Day10;
+use aoc_2020::day11::Day11;
+use aoc_2020::day12::Day12;
+use aoc_2020::day13::Day13;
+use aoc_2020::day14::Day14;
+use aoc_2020::day15::Day15;
+use aoc_2020::day16::Day16;
+use aoc_2020::day2::Day2;
+use aoc_2020::day3::Day3;
+use aoc_2020::day4::Day4;
+use aoc_2020::day5::Day5;
+use aoc_2020::day6::Day6;
+use aoc_2020::day7::Day7;
+use aoc_2020::day8::Day8;
+use aoc_2020::day9::Day9;
+use aoc_2020::{input_path, run};
+
+const USAGE: &str = "Usage: aoc <day> [<part>] [<input>]
+
+When <input> is missing it's taken from AOC_INPUT or defaults to
+files/day<day>/input.txt, use - to read it from stdin.";
+
+/// Parse a number from the command line, naming `what` it is on failure.
+fn parse_arg(arg: &str, what: &str) -> AnyResult<u32> {
+    arg.parse()
+        .map_err(|_| anyhow!("Invalid {}: {}\n{}", what, arg, USAGE))
+}
+
+/// Read the day, the optional part and the input path given to the binary,
+/// a lone second argument is taken as the part only if it's a number.
+fn parse_args() -> AnyResult<(u32, Option<u32>, String)> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (day, part, path) = match args.as_slice() {
+        [day] => (parse_arg(day, "day")?, None, None),
+        [day, part] if part.parse::<u32>().is_ok() => {
+            (parse_arg(day, "day")?, Some(parse_arg(part, "part")?), None)
+        }
+        [day, path] => (parse_arg(day, "day")?, None, Some(path.to_owned())),
+        [day, part, path] => (
+            parse_arg(day, "day")?,
+            Some(parse_arg(part, "part")?),
+            Some(path.to_owned()),
+        ),
+        _ => bail!(USAGE),
+    };
+
+    Ok((day, part, input_path(day, path)))
+}
+
+fn try_main() -> AnyResult<()> {
+    let (day, part, path) = parse_args()?;
+    match day {
+        1 => run::<Day1>(day, part, path),
+        2 => run::<Day2>(day, part, path),
+        3 => run::<Day3>(day, part, path),
+        4 => run::<Day4>(day, part, path),
+        5 => run::<Day5>(day, part, path),
+        6 => run::<Day6>(day, part, path),
+        7 => run::<Day7>(day, part, path),
+        8 => run::<Day8>(day, part, path),
+        9 => run::<Day9>(day, part, path),
+        10 => run::<Day10>(day, part, path),
+        11 => run::<Day11>(day, part, path),
+        12 => run::<Day12>(day, part, path),
+        13 => run::<Day13>(day, part, path),
+        14 => run::<Day14>(day, part, path),
+        15 => run::<Day15>(day, part, path),
+        16 => run::<Day16>(day, part, path),
+        day => bail!("Day {} is not solved yet", day),
+    }
+}
+
+fn main() {
+    if let Err(err) = try_main() {
+        eprintln!("Error: {:#}", err);
+        process::exit(1);
+    }
+}
@@ -1,82 +1,46 @@
-use std::collections::HashMap;
+use std::env;
 
-use anyhow::{anyhow, Result as AnyResult};
+use anyhow::{bail, Context, Result as AnyResult};
+use aoc_2020::day15::{parse_starting_numbers, spoken_at, Day15};
+use aoc_2020::{input_path, read_input, solve, take_input_arg};
 
-#[derive(Debug, Clone)]
-struct NumberInfo {
-    last_idx: usize,
-    before_last_idx: Option<usize>,
-}
+const USAGE: &str = "Usage: day15 [<input>|<numbers>] [<turn>]";
 
-#[derive(Debug, Clone)]
-struct Game {
-    memory: HashMap<usize, NumberInfo>,
-    turn: usize,
-    last_num: usize,
-}
+/// Usage: day15 [<input>|<numbers>] [<turn>]
+///
+/// The starting numbers are read from `<input>` (see `input_path`) unless
+/// they're given directly, e.g. `day15 0,3,6`. Without `<turn>` both parts
+/// are solved, otherwise only the number spoken on that turn is printed.
+fn main() -> AnyResult<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let numbers = |arg: &str| {
+        arg.chars()
+            .all(|chr| chr.is_ascii_digit() || chr == ',' || chr == ' ')
+    };
 
-impl Game {
-    fn new(starting_numbers: &[usize]) -> Option<Self> {
-        let mut memory = HashMap::new();
-        for (idx, num) in starting_numbers.iter().enumerate() {
-            memory.insert(
-                *num,
-                NumberInfo {
-                    last_idx: idx,
-                    before_last_idx: None,
-                },
+    // cargo run --bin day15 --release, go brrrr
+    let input = match take_input_arg(&mut args, numbers) {
+        Some(path) => read_input(path)?,
+        None if args.is_empty() => read_input(input_path(15, None))?,
+        None => args.remove(0),
+    };
+
+    let turn = match args.as_slice() {
+        [] => None,
+        [turn] => Some(turn.parse::<usize>().context("Invalid turn")?),
+        _ => bail!(USAGE),
+    };
+
+    match turn {
+        None => solve::<Day15>(15, None, &input),
+        Some(turn) => {
+            let starting_numbers = parse_starting_numbers(&input)?;
+            println!(
+                "Day 15, Turn {}: {}",
+                turn,
+                spoken_at(&starting_numbers, turn)?
             );
+            Ok(())
         }
-
-        let turn = starting_numbers.len();
-        let last_num = *starting_numbers.last()?;
-
-        Some(Self {
-            memory,
-            turn,
-            last_num,
-        })
     }
-
-    fn simulate(&mut self, until: usize) {
-        while self.turn <= until {
-            if let Some(info) = self.memory.get(&self.last_num) {
-                if let Some(before_last_idx) = info.before_last_idx {
-                    self.last_num = info.last_idx - before_last_idx;
-                } else {
-                    self.last_num = 0;
-                };
-
-                if let Some(info) = self.memory.get_mut(&self.last_num) {
-                    info.before_last_idx = Some(info.last_idx);
-                    info.last_idx = self.turn;
-                } else {
-                    self.memory.insert(
-                        self.last_num,
-                        NumberInfo {
-                            last_idx: self.turn,
-                            before_last_idx: None,
-                        },
-                    );
-                }
-            }
-
-            self.turn += 1;
-        }
-    }
-}
-
-fn main() -> AnyResult<()> {
-    let mut game = Game::new(&[1, 0, 18, 10, 19, 6]).ok_or_else(|| anyhow!("Invalid array"))?;
-    // Turn 2020 starting from 1
-    game.simulate(2020 - 1);
-    println!("Day 15, Part 1: {}", game.last_num);
-
-    let mut game = Game::new(&[1, 0, 18, 10, 19, 6]).ok_or_else(|| anyhow!("Invalid array"))?;
-    // Turn 30000000 starting from 1
-    // cargo run --bin day15 --release, go brrrr
-    game.simulate(30000000 - 1);
-    println!("Day 15, Part 2: {}", game.last_num);
-
-    Ok(())
 }
@@ -1,86 +1,60 @@
-use anyhow::Result as AnyResult;
-use aoc_2020::lines;
-
-struct Components(Vec<usize>, char, String);
-
-/// Separate a line with the following format:
-/// <number>-<number> <char>: <strig>
-/// Into their basic components: ([<number>], <char>, <string>)
-fn split_terms(line: &str) -> Components {
-    let splitted = line.split(' ').collect::<Vec<_>>();
-
-    let numbers = splitted[0]
-        .split('-')
-        .filter_map(|n| n.parse().ok())
-        .collect::<Vec<_>>();
-
-    let chr = splitted[1].chars().next().unwrap();
-    let string = splitted[2].to_owned();
-
-    Components(numbers, chr, string)
-}
-
-/// Load file contents and build vec of components.
-fn load_and_split() -> AnyResult<Vec<Components>> {
-    Ok(lines("files/day2/input.txt")?
-        .map(|line| split_terms(&line))
-        .collect())
-}
+use std::env;
 
-/// Check whether a password is valid given the first interpretation.
-/// Count how many times `letter` appears in the password, then check if
-/// it's betweem the range.
-fn is_valid_pass_part1(min_times: usize, max_times: usize, letter: char, password: &str) -> bool {
-    let letter_count = password.chars().filter(|c| *c == letter).count();
-    (min_times <= letter_count) && (letter_count <= max_times)
-}
+use anyhow::Result as AnyResult;
+use aoc_2020::day2::{count_valid, parse_policy, Audit, Day2, ExactlyOne, RangeCount};
+use aoc_2020::{input_path, read_input, run, take_input_arg, Solution};
+
+/// Usage: day2 [--table|--json] [<input>] [<policy>...]
+///
+/// Without policies nor a report format both parts are solved, otherwise the
+/// number of valid passwords under each policy is printed, see `parse_policy`
+/// for their names, e.g. `day2 - "one-of and regex:^[a-z]+$"`.
+///
+/// `--table` and `--json` print the verdict of every entry instead, as a
+/// table or as JSON lines, under the given policies or the two of the puzzle.
+fn main() -> AnyResult<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let format = match args.first() {
+        Some(arg) if arg == "--table" || arg == "--json" => Some(args.remove(0)),
+        _ => None,
+    };
+
+    let path = take_input_arg(&mut args, |arg| parse_policy(arg).is_ok());
+    let policies = args;
+    if format.is_none() && policies.is_empty() {
+        return run::<Day2>(2, None, input_path(2, path));
+    }
 
-/// Count all the passwords that are valid given the first interpretation.
-fn part1(entries: &[Components]) -> usize {
-    entries
+    let mut policies = policies
         .iter()
-        .filter(|Components(times, letter, password)| {
-            is_valid_pass_part1(times[0], times[1], *letter, &password)
-        })
-        .count()
-}
+        .map(|spec| parse_policy(spec))
+        .collect::<AnyResult<Vec<_>>>()?;
+
+    let entries = Day2::parse(&read_input(input_path(2, path))?)?;
+    match format.as_deref() {
+        None => {
+            for policy in policies {
+                println!(
+                    "Day 2, {}: {}",
+                    policy,
+                    count_valid(&entries, policy.as_ref())
+                );
+            }
+        }
 
-/// Check whether a password is valid given the second interpretation.
-/// There should be exactly one and only one char that matches `letter`
-/// in one of the given positions.
-fn is_valid_pass_part2(positions: &[usize], letter: char, password: &str) -> bool {
-    let chars = password.chars().collect::<Vec<_>>();
+        Some(format) => {
+            if policies.is_empty() {
+                policies = vec![Box::new(RangeCount), Box::new(ExactlyOne)];
+            }
 
-    let mut found_one = false;
-    for position in positions {
-        if let Some(chr) = chars.get(*position - 1) {
-            if found_one && *chr == letter {
-                return false;
-            } else if *chr == letter {
-                found_one = true;
+            let audit = Audit::new(&entries, &policies);
+            if format == "--json" {
+                audit.json_lines().for_each(|line| println!("{}", line));
+            } else {
+                print!("{}", audit);
             }
-        } else {
-            return false;
         }
     }
 
-    found_one
-}
-
-/// Count all the passwords that are valid given the first interpretation.
-fn part2(entries: &[Components]) -> usize {
-    entries
-        .iter()
-        .filter(|Components(positions, letter, password)| {
-            is_valid_pass_part2(positions, *letter, &password)
-        })
-        .count()
-}
-
-fn main() -> AnyResult<()> {
-    let entries = load_and_split()?;
-    println!("Day 2, Part 1: {}", part1(&entries));
-    println!("Day 2, Part 2: {}", part2(&entries));
-
     Ok(())
 }
@@ -1,61 +1,102 @@
-use anyhow::Result as AnyResult;
-use aoc_2020::lines;
+use std::env;
+use std::ops::{Range, RangeInclusive};
 
-/// Hold the map as a matrix.
-struct Map {
-    map: Vec<Vec<char>>,
+use anyhow::{anyhow, bail, Context, Result as AnyResult};
+use aoc_2020::day3::{Day3, Goal, Slope};
+use aoc_2020::{input_path, read_input, run_default, take_input_arg, Solution};
+
+const USAGE: &str = "Usage: day3 [<input>] [<right>,<down>...]
+       day3 [<input>] --fewest|--most <rights> <downs>
+       day3 [<input>] --path <right>,<down>
+       day3 [<input>] --render [--colour] [--columns <bounds>] [--rows <bounds>] <right>,<down>...
+
+Without slopes both parts are solved. Given slopes, the trees found along
+each one and their product are printed. --fewest and --most look for the
+slope with the fewest or most trees within the bounds, e.g. 1-7 and 1-2.
+--path prints every square visited along a slope. --render draws the map with
+the squares visited along each slope marked, O if open and X if it's a tree,
+only the map's own width unless other columns are given, e.g. --columns 0-79,
+and optionally only within some rows.";
+
+/// Parse bounds written as `<min>-<max>` or as a single number.
+fn parse_bounds(arg: &str) -> AnyResult<RangeInclusive<usize>> {
+    let (min, max) = arg.split_once('-').unwrap_or((arg, arg));
+    let parse = |num: &str| {
+        num.parse::<usize>()
+            .with_context(|| format!("Invalid bounds: {:?}\n{}", arg, USAGE))
+    };
+
+    Ok(parse(min)?..=parse(max)?)
 }
 
-impl Map {
-    /// Load and build the map
-    fn build_map() -> AnyResult<Self> {
-        let map = lines("files/day3/input.txt")?
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+fn main() -> AnyResult<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+
+    let path = take_input_arg(&mut args, |arg| arg.starts_with("--") || arg.contains(','));
 
-        Ok(Self { map })
+    if args.is_empty() {
+        return run_default::<Day3>(3);
     }
 
-    /// Traverse the map by turning right `right` steps and down `down` steps
-    /// repeteadly until we reach the bottom part of the map. Returns the number
-    /// of trees we found.
-    fn traverse(&self, right: usize, down: usize) -> usize {
-        let max_y = self.map.len();
-        let max_x = self.map[0].len();
-        let (mut x, mut y) = (0, 0);
-
-        let mut tree_count = 0;
-        while (y + down) < max_y {
-            x = (x + right) % max_x;
-            y += down;
-
-            if self.map[y][x] == '#' {
-                tree_count += 1;
+    let map = Day3::parse(&read_input(input_path(3, path))?)?;
+    match args.as_slice() {
+        [flag, rights, downs] if flag == "--fewest" || flag == "--most" => {
+            let goal = if flag == "--fewest" {
+                Goal::FewestTrees
+            } else {
+                Goal::MostTrees
+            };
+
+            let (slope, trees) = map
+                .best_slope(parse_bounds(rights)?, parse_bounds(downs)?, goal)
+                .ok_or_else(|| anyhow!("No slopes within the bounds"))?;
+            println!("Day 3, Slope {}: {}", slope, trees);
+        }
+
+        [flag, options @ ..] if flag == "--render" => {
+            let mut renderer = map.render();
+            let mut options = options.iter();
+            while let Some(option) = options.next() {
+                let mut bounds = || -> AnyResult<Range<usize>> {
+                    let bounds = options.next().ok_or_else(|| anyhow!(USAGE))?;
+                    let bounds = parse_bounds(bounds)?;
+                    Ok(*bounds.start()..bounds.end() + 1)
+                };
+
+                renderer = match option.as_str() {
+                    "--colour" => renderer.colour(true),
+                    "--columns" => renderer.columns(bounds()?),
+                    "--rows" => renderer.rows(bounds()?),
+                    slope => renderer.slope(slope.parse()?),
+                };
+            }
+
+            print!("{}", renderer);
+        }
+
+        [flag, slope] if flag == "--path" => {
+            for ((x, y), square) in map.path(slope.parse()?) {
+                println!("{},{} {}", x, y, square);
             }
         }
 
-        tree_count
-    }
-}
+        slopes if slopes.iter().all(|arg| !arg.starts_with("--")) => {
+            let slopes = slopes
+                .iter()
+                .map(|slope| slope.parse())
+                .collect::<AnyResult<Vec<Slope>>>()?;
 
-/// Initial traverse.
-fn part1(map: &Map) -> usize {
-    map.traverse(3, 1)
-}
+            let (trees, product) = map.survey(&slopes);
+            for (slope, trees) in slopes.iter().zip(trees) {
+                println!("Day 3, Slope {}: {}", slope, trees);
+            }
 
-/// Traverse with different configurations and return the product.
-fn part2(map: &Map) -> usize {
-    let params = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
-    params
-        .iter()
-        .map(|(right, down)| map.traverse(*right, *down))
-        .product()
-}
+            let product = product.ok_or_else(|| anyhow!("Product overflows"))?;
+            println!("Day 3, Product: {}", product);
+        }
 
-fn main() -> AnyResult<()> {
-    let map = Map::build_map()?;
-    println!("Day 3, Part 1: {}", part1(&map));
-    println!("Day 3, Part 2: {}", part2(&map));
+        _ => bail!(USAGE),
+    }
 
     Ok(())
 }
@@ -1,65 +1,36 @@
-use anyhow::Result as AnyResult;
-use aoc_2020::lines;
+use std::env;
 
-/// Returns a partitioner function.
-fn partitioner(l: char, r: char) -> impl Fn(&str, usize, usize) -> usize {
-    move |arr: &str, mut lo: usize, mut hi: usize| -> usize {
-        for d in arr.chars() {
-            match d {
-                d if d == l => hi = (hi + lo) / 2,
-                d if d == r => lo = (hi + lo) / 2 + 1,
-                _ => panic!("Unexpected char in string"),
-            }
-        }
+use anyhow::{bail, Result as AnyResult};
+use aoc_2020::day5::{Cabin, Day5, SeatMap};
+use aoc_2020::{input_path, read_input, run_default, take_input_arg, Solution};
 
-        lo
+/// Usage: day5 [<input>] [--map]
+///
+/// `--map` draws the cabin with the seats taken, followed by the runs of
+/// empty seats and the seats issued more than once.
+fn main() -> AnyResult<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let path = take_input_arg(&mut args, |arg| arg.starts_with("--"));
+    match args.as_slice() {
+        [] => return run_default::<Day5>(5),
+        [flag] if flag == "--map" => {}
+        _ => bail!("Usage: day5 [<input>] [--map]"),
     }
-}
-
-/// Get the seat id for a given line.
-fn get_seat_id(line: &str) -> usize {
-    let (rows, cols) = (&line[..7], &line[7..]);
-    let row = partitioner('F', 'B')(rows, 0, 127);
-    let col = partitioner('L', 'R')(cols, 0, 7);
-
-    row * 8 + col
-}
-
-/// Load contents of file and map each line to its seat id.
-fn build_seat_ids() -> AnyResult<Vec<usize>> {
-    Ok(lines("files/day5/input.txt")?
-        .map(|line| get_seat_id(&line))
-        .collect())
-}
 
-/// Receives a sorted array of seat ids, returns the max seat id, i.e. the last.
-fn part1(seat_ids: &[usize]) -> Option<usize> {
-    seat_ids.last().copied()
-}
+    let seat_ids = Day5::parse(&read_input(input_path(5, path))?)?;
+    let map = SeatMap::from_ids(Cabin::PUZZLE, &seat_ids)?;
+    print!("{}", map);
 
-/// Received a sorted array of seat ids, traverses the array, at each element
-/// we expect last + 1, if it's missing we test if the two contigous elements
-/// are equal to last and last + 2.
-fn part2(seat_ids: &[usize]) -> Option<usize> {
-    let mut expecting = seat_ids[0] + 1;
-    for (idx, seat_id) in seat_ids.iter().enumerate().skip(1) {
-        if *seat_id != expecting
-            && (seat_ids[idx - 1] == expecting - 1 && seat_ids[idx] == expecting + 1)
-        {
-            return Some(expecting);
+    for run in map.empty_runs() {
+        match run.len() {
+            1 => println!("Empty seat: {}", run.start),
+            _ => println!("Empty seats: {}-{}", run.start, run.end - 1),
         }
-
-        expecting = seat_id + 1;
     }
 
-    None
-}
-
-fn main() -> AnyResult<()> {
-    let mut seat_ids = build_seat_ids()?;
-    seat_ids.sort_unstable();
-    println!("Day 5, Part 1: {:?}", part1(&seat_ids));
-    println!("Day 5, Part 2: {:?}", part2(&seat_ids));
+    for (pass, passes) in map.duplicates() {
+        println!("Seat {} ({}) issued {} times", pass.id(), pass, passes);
+    }
 
     Ok(())
 }
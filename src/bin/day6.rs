@@ -1,87 +1,50 @@
-use std::collections::HashSet;
+use std::env;
 
-use anyhow::Result as AnyResult;
-use aoc_2020::lines;
+use anyhow::{bail, Context, Result as AnyResult};
+use aoc_2020::day6::{count_answers, question_stats, Day6};
+use aoc_2020::{input_path, read_input, run_default, take_input_arg, Solution};
 
-/// Groups of 'yes' answers.
-type GroupAnswers = HashSet<char>;
+const USAGE: &str = "Usage: day6 [<input>] [--exactly <k>|--majority|--stats]
 
-/// Group together all answers from each group to which anyone
-/// in it answered 'yes'.
-fn anyone_yes_answers() -> AnyResult<Vec<GroupAnswers>> {
-    let mut groups = Vec::new();
-    let mut group_yes_answers = HashSet::new();
+--exactly counts the questions answered 'yes' by exactly k people of a group,
+--majority the ones answered by more than half of a group, and --stats prints
+how each question was answered across every group.";
 
-    for line in lines("files/day6/input.txt")? {
-        if line.trim().is_empty() {
-            // Finished collecting answers from one group
-            groups.push(group_yes_answers);
-            group_yes_answers = HashSet::new();
-        } else {
-            group_yes_answers.extend(line.chars());
-        }
-    }
-
-    if !group_yes_answers.is_empty() {
-        groups.push(group_yes_answers);
+fn main() -> AnyResult<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let path = take_input_arg(&mut args, |arg| arg.starts_with("--"));
+    if args.is_empty() {
+        return run_default::<Day6>(6);
     }
 
-    Ok(groups)
-}
+    let groups = || Day6::parse(&read_input(input_path(6, path.clone()))?);
+    match args.as_slice() {
+        [flag, k] if flag == "--exactly" => {
+            let k: usize = k
+                .parse()
+                .with_context(|| format!("Invalid number of people: {:?}\n{}", k, USAGE))?;
 
-/// Group together all answers from each group to which everyone
-/// in it answered 'yes'.
-fn everyone_yes_answers() -> AnyResult<Vec<GroupAnswers>> {
-    let mut groups = Vec::new();
-    let mut group_yes_answers = HashSet::new();
-
-    // Used to initialize the group yes answers set.
-    let mut first_person = true;
-
-    for line in lines("files/day6/input.txt")? {
-        if line.trim().is_empty() {
-            // Finished collecting answers from one group
-            groups.push(group_yes_answers);
-            group_yes_answers = HashSet::new();
-
-            first_person = true;
-        } else {
-            let person_yes_answers: HashSet<_> = line.chars().collect();
+            let count = count_answers(&groups()?, |group| group.exactly(k));
+            println!("Day 6, Exactly {}: {}", k, count);
+        }
 
-            group_yes_answers = if first_person {
-                person_yes_answers
-            } else {
-                group_yes_answers
-                    .intersection(&person_yes_answers)
-                    .copied()
-                    .collect()
-            };
+        [flag] if flag == "--majority" => {
+            let count = count_answers(&groups()?, |group| group.majority());
+            println!("Day 6, Majority: {}", count);
+        }
 
-            first_person = false;
+        [flag] if flag == "--stats" => {
+            println!("question  people  anyone  everyone");
+            for stats in question_stats(&groups()?) {
+                println!(
+                    "{:>8}  {:>6}  {:>6}  {:>8}",
+                    stats.question, stats.people, stats.anyone, stats.everyone
+                );
+            }
         }
-    }
 
-    if !group_yes_answers.is_empty() {
-        groups.push(group_yes_answers);
+        _ => bail!("Unknown arguments {:?}\n{}", args, USAGE),
     }
 
-    Ok(groups)
-}
-
-/// Count 'yes' answers from an array of group answers.
-fn count_yes_answers(groups: &[GroupAnswers]) -> usize {
-    groups.iter().map(|group| group.len()).sum()
-}
-
-fn main() -> AnyResult<()> {
-    println!(
-        "Day 6, Part 1: {}",
-        count_yes_answers(&anyone_yes_answers()?)
-    );
-    println!(
-        "Day 6, Part 2: {}",
-        count_yes_answers(&everyone_yes_answers()?)
-    );
-
     Ok(())
 }
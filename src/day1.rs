@@ -0,0 +1,355 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result as AnyResult};
+use itertools::Itertools;
+
+use crate::{parse_lines, Solution};
+
+/// Sum of the entries the puzzle looks for.
+pub const TARGET: i64 = 2020;
+
+/// Entries of an expense report, kept in the order they were given.
+#[derive(Debug, Clone)]
+pub struct ExpenseReport {
+    entries: Vec<i32>,
+    /// Indices of `entries` sorted by their value.
+    sorted: Vec<usize>,
+    /// Sums of the first entries in the sorted order, `prefix[i]` is the sum
+    /// of the `i` smallest ones.
+    prefix: Vec<i64>,
+}
+
+/// Distinct entries of a report that sum to some target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    /// Indices of the entries in the report, in increasing order.
+    pub indices: Vec<usize>,
+    /// Entries at `indices`.
+    pub entries: Vec<i32>,
+}
+
+impl Combination {
+    /// Product of the entries, `None` if it overflows.
+    pub fn product(&self) -> Option<i64> {
+        self.entries
+            .iter()
+            .try_fold(1i64, |acc, entry| acc.checked_mul(*entry as i64))
+    }
+}
+
+impl ExpenseReport {
+    pub fn new(entries: Vec<i32>) -> Self {
+        let mut sorted: Vec<_> = (0..entries.len()).collect();
+        sorted.sort_unstable_by_key(|&idx| entries[idx]);
+
+        let mut prefix = vec![0];
+        prefix.extend(sorted.iter().scan(0, |acc, &idx| {
+            *acc += entries[idx] as i64;
+            Some(*acc)
+        }));
+
+        Self {
+            entries,
+            sorted,
+            prefix,
+        }
+    }
+
+    pub fn entries(&self) -> &[i32] {
+        &self.entries
+    }
+
+    /// Find `k` distinct entries (i.e. at different indices) that sum to
+    /// `target`, if there are several combinations any of them is returned.
+    ///
+    /// Pairs are found with two pointers moving over the sorted entries in
+    /// O(n), and triplets fixing each entry and looking for a pair in the
+    /// entries after it in O(n^2). For bigger tuples all the combinations of
+    /// half the size are stored by their sum, and then the combinations of the
+    /// other half look up their complement, which takes O(n^(k/2)) time and
+    /// memory instead of O(n^k).
+    pub fn find_sum(&self, target: i64, k: usize) -> Option<Combination> {
+        if k > self.entries.len() {
+            return None;
+        }
+
+        let indices = match k {
+            0 if target == 0 => Vec::new(),
+            0 => return None,
+            1 => vec![self.find_single(target)?],
+            2 => {
+                let (a, b) = self.find_pair(0, target)?;
+                vec![a, b]
+            }
+            3 => self.find_triplet(target)?,
+            _ => self.meet_in_the_middle(target, k)?,
+        };
+
+        Some(self.combination(indices))
+    }
+
+    /// Iterate over every combination of `k` distinct entries (by index) that
+    /// sum to `target`. Repeated values in the report give one combination for
+    /// each index they appear at.
+    pub fn sums(&self, target: i64, k: usize) -> Sums<'_> {
+        Sums {
+            report: self,
+            target,
+            k,
+            stack: vec![0],
+            sum: 0,
+        }
+    }
+
+    /// Count how many combinations `sums` would yield without building them.
+    /// Pairs are counted in a single pass over the sorted entries, grouping
+    /// repeated values, so deeper tuples only need to enumerate `k - 2`
+    /// entries.
+    pub fn count_sums(&self, target: i64, k: usize) -> u64 {
+        self.count_from(0, k, target)
+    }
+
+    fn count_from(&self, from: usize, k: usize, target: i64) -> u64 {
+        let len = self.sorted.len();
+        if k == 0 {
+            return (target == 0) as u64;
+        }
+
+        if from + k > len || self.min_sum(from, k) > target || self.max_sum(k) < target {
+            return 0;
+        }
+
+        match k {
+            1 => {
+                let values = &self.sorted[from..];
+                let value = |idx: &usize| self.entries[*idx] as i64;
+                let start = values.partition_point(|idx| value(idx) < target);
+                let end = values.partition_point(|idx| value(idx) <= target);
+                (end - start) as u64
+            }
+
+            2 => self.count_pairs(from, target),
+
+            _ => (from..=len - k)
+                .take_while(|&pos| self.min_sum(pos, k) <= target)
+                .map(|pos| self.count_from(pos + 1, k - 1, target - self.sorted_value(pos)))
+                .sum(),
+        }
+    }
+
+    /// Count the pairs summing `target` among the entries from position
+    /// `from` in the sorted order.
+    fn count_pairs(&self, from: usize, target: i64) -> u64 {
+        let (mut lo, mut hi) = (from, self.sorted.len() - 1);
+        let mut count = 0;
+
+        while lo < hi {
+            let (low, high) = (self.sorted_value(lo), self.sorted_value(hi));
+            match (low + high).cmp(&target) {
+                Ordering::Less => lo += 1,
+                Ordering::Greater => hi -= 1,
+                Ordering::Equal if low == high => {
+                    // Every pair of entries left has the same value.
+                    let run = (hi - lo + 1) as u64;
+                    count += run * (run - 1) / 2;
+                    break;
+                }
+                Ordering::Equal => {
+                    let lows = (lo..hi).take_while(|&pos| self.sorted_value(pos) == low);
+                    let lows = lows.count();
+                    let highs = (lo + 1..=hi).rev();
+                    let highs = highs.take_while(|&pos| self.sorted_value(pos) == high);
+                    let highs = highs.count();
+
+                    count += (lows * highs) as u64;
+                    lo += lows;
+                    hi -= highs;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Smallest sum of `k` entries starting at position `from` in the sorted
+    /// order.
+    fn min_sum(&self, from: usize, k: usize) -> i64 {
+        self.prefix[from + k] - self.prefix[from]
+    }
+
+    /// Biggest sum of any `k` entries.
+    fn max_sum(&self, k: usize) -> i64 {
+        let len = self.sorted.len();
+        self.prefix[len] - self.prefix[len - k]
+    }
+
+    fn combination(&self, mut indices: Vec<usize>) -> Combination {
+        indices.sort_unstable();
+        let entries = indices.iter().map(|&idx| self.entries[idx]).collect();
+        Combination { indices, entries }
+    }
+
+    /// Value of the entry at `pos` in the sorted order.
+    fn sorted_value(&self, pos: usize) -> i64 {
+        self.entries[self.sorted[pos]] as i64
+    }
+
+    fn sum(&self, indices: &[usize]) -> i64 {
+        indices.iter().map(|&idx| self.entries[idx] as i64).sum()
+    }
+
+    fn find_single(&self, target: i64) -> Option<usize> {
+        let pos = self
+            .sorted
+            .binary_search_by_key(&target, |&idx| self.entries[idx] as i64)
+            .ok()?;
+        Some(self.sorted[pos])
+    }
+
+    /// Find a pair summing `target` among the entries from position `from` in
+    /// the sorted order.
+    fn find_pair(&self, from: usize, target: i64) -> Option<(usize, usize)> {
+        let (mut lo, mut hi) = (from, self.sorted.len().checked_sub(1)?);
+        while lo < hi {
+            match (self.sorted_value(lo) + self.sorted_value(hi)).cmp(&target) {
+                Ordering::Less => lo += 1,
+                Ordering::Greater => hi -= 1,
+                Ordering::Equal => return Some((self.sorted[lo], self.sorted[hi])),
+            }
+        }
+
+        None
+    }
+
+    fn find_triplet(&self, target: i64) -> Option<Vec<usize>> {
+        (0..self.sorted.len()).find_map(|pos| {
+            let (b, c) = self.find_pair(pos + 1, target - self.sorted_value(pos))?;
+            Some(vec![self.sorted[pos], b, c])
+        })
+    }
+
+    fn meet_in_the_middle(&self, target: i64, k: usize) -> Option<Vec<usize>> {
+        let len = self.entries.len();
+        let half = k / 2;
+
+        let mut halves: HashMap<i64, Vec<Vec<usize>>> = HashMap::new();
+        for combination in (0..len).combinations(half) {
+            halves
+                .entry(self.sum(&combination))
+                .or_default()
+                .push(combination);
+        }
+
+        (0..len).combinations(k - half).find_map(|combination| {
+            let complements = halves.get(&(target - self.sum(&combination)))?;
+            let complement = complements
+                .iter()
+                .find(|other| other.iter().all(|idx| !combination.contains(idx)))?;
+            Some(combination.iter().chain(complement).copied().collect())
+        })
+    }
+}
+
+/// Iterator over the combinations of entries of a report that sum to some
+/// target, see `ExpenseReport::sums`.
+#[derive(Debug, Clone)]
+pub struct Sums<'a> {
+    report: &'a ExpenseReport,
+    target: i64,
+    k: usize,
+    /// Positions in the sorted order of the entries picked so far, followed
+    /// by the position of the next candidate. Empty once exhausted.
+    stack: Vec<usize>,
+    /// Sum of the entries picked so far.
+    sum: i64,
+}
+
+impl<'a> Sums<'a> {
+    /// Drop the current candidate and move on to the one after the last
+    /// entry picked.
+    fn backtrack(&mut self) {
+        self.stack.pop();
+        if let Some(last) = self.stack.last_mut() {
+            self.sum -= self.report.sorted_value(*last);
+            *last += 1;
+        }
+    }
+}
+
+impl<'a> Iterator for Sums<'a> {
+    type Item = Combination;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let report = self.report;
+        let len = report.sorted.len();
+
+        if self.k == 0 {
+            let found = !self.stack.is_empty() && self.target == 0;
+            self.stack.clear();
+            return found.then(|| report.combination(Vec::new()));
+        }
+
+        while let Some(&pos) = self.stack.last() {
+            let left = self.k - (self.stack.len() - 1);
+
+            // Entries are visited in increasing order, so once the smallest
+            // sum reachable is too big no later candidate can do better.
+            if pos + left > len
+                || self.sum + report.min_sum(pos, left) > self.target
+                || self.sum + report.max_sum(left) < self.target
+            {
+                self.backtrack();
+                continue;
+            }
+
+            if left > 1 {
+                self.sum += report.sorted_value(pos);
+                self.stack.push(pos + 1);
+                continue;
+            }
+
+            let found = self.sum + report.sorted_value(pos) == self.target;
+            let indices = found.then(|| self.stack.iter().map(|&pos| report.sorted[pos]).collect());
+            *self.stack.last_mut()? += 1;
+
+            if let Some(indices) = indices {
+                return Some(report.combination(indices));
+            }
+        }
+
+        None
+    }
+}
+
+/// Product of `k` distinct entries of `report` that sum to `TARGET`.
+fn product_of(report: &ExpenseReport, k: usize) -> AnyResult<i64> {
+    report
+        .find_sum(TARGET, k)
+        .ok_or_else(|| anyhow!("No {} entries sum {}", k, TARGET))?
+        .product()
+        .ok_or_else(|| anyhow!("Product overflows"))
+}
+
+/// Day 1: Report Repair.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = ExpenseReport;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Ok(ExpenseReport::new(parse_lines::<i32>(input)?))
+    }
+
+    /// Product of the two entries that sum 2020.
+    fn part1(report: &Self::Input) -> AnyResult<i64> {
+        product_of(report, 2)
+    }
+
+    /// Product of the three entries that sum 2020.
+    fn part2(report: &Self::Input) -> AnyResult<i64> {
+        product_of(report, 3)
+    }
+}
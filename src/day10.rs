@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result as AnyResult};
+
+use crate::{parse_lines, Solution};
+
+/// Expects that `adapters` is sorted.
+pub fn diffs(adapters: &[usize]) -> HashMap<usize, usize> {
+    let mut diffs = HashMap::new();
+
+    for window in adapters.windows(2) {
+        let diff = window[1] - window[0];
+        *diffs.entry(diff).or_insert(0) += 1;
+    }
+
+    diffs
+}
+
+/// Calculate the difference vector and then count how many valid combinations
+/// are in the groups of 1's with length greater or equal to 2, multiply those
+/// combinations to get the full number of valid combinations. The magic is in
+/// (len * (len - 1)) / 2 + 1, I'll need some time to fully explain how does it
+/// works, but it only works if the vector of differences contains 1's and 3's.
+pub fn possibilities(adapters: &[usize]) -> usize {
+    let diffs = adapters.windows(2).map(|w| w[1] - w[0]);
+
+    let mut acc = 1;
+    let mut len = 0;
+
+    for diff in diffs {
+        if diff != 1 {
+            if len > 1 {
+                acc *= (len * (len - 1)) / 2 + 1
+            }
+
+            len = 0;
+        } else {
+            len += 1;
+        }
+    }
+
+    acc
+}
+
+/// Day 10: Adapter Array.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Parse the adapters, add the charging outlet and the device built-in
+    /// adapter and sort them.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        let mut adapters: Vec<usize> = parse_lines(input)?;
+
+        // Charging outlet.
+        adapters.push(0);
+        // Sort now.
+        adapters.sort_unstable();
+        // Device built-in adapter.
+        adapters.push(adapters.last().ok_or_else(|| anyhow!("Empty array"))? + 3);
+
+        Ok(adapters)
+    }
+
+    /// Multiply the number of 1-jolt differences by the number of 3-jolt differences.
+    fn part1(adapters: &Self::Input) -> AnyResult<usize> {
+        let diffs = diffs(adapters);
+        let ones = diffs.get(&1).copied().unwrap_or(0);
+        let threes = diffs.get(&3).copied().unwrap_or(0);
+        Ok(ones * threes)
+    }
+
+    /// Count the distinct arrangements of adapters.
+    fn part2(adapters: &Self::Input) -> AnyResult<usize> {
+        Ok(possibilities(adapters))
+    }
+}
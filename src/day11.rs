@@ -0,0 +1,137 @@
+use anyhow::{bail, Result as AnyResult};
+
+use crate::grid::NEIGHBOURS_8;
+use crate::{Grid, Solution};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum State {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum NewStateOp {
+    PartOne,
+    PartTwo,
+}
+
+#[derive(Debug, Clone)]
+pub struct Layout {
+    map: Grid<State>,
+}
+
+impl Layout {
+    pub fn parse(input: &str) -> AnyResult<Self> {
+        let map = Grid::parse(input, |c| {
+            Ok(match c {
+                '.' => State::Floor,
+                'L' => State::Empty,
+                '#' => State::Occupied,
+                c => bail!("Bad char: {}", c),
+            })
+        })?;
+
+        Ok(Self { map })
+    }
+
+    pub fn occupied(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, state)| **state == State::Occupied)
+            .count()
+    }
+
+    fn step(&mut self, op: NewStateOp) -> bool {
+        let temp_map = self.map.map(|(x, y), _| match op {
+            NewStateOp::PartOne => self.new_state_part_one(x, y),
+            NewStateOp::PartTwo => self.new_state_part_two(x, y),
+        });
+
+        if self.map != temp_map {
+            self.map = temp_map;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Count how many states are occupied around (x, y) and return the new
+    /// state of (x, y) based on that.
+    fn new_state_part_one(&self, x: usize, y: usize) -> State {
+        let state = self.map[(x, y)];
+        if let State::Floor = state {
+            return State::Floor;
+        }
+
+        let occupied = self
+            .map
+            .neighbours8(x as i64, y as i64)
+            .filter(|state| **state == State::Occupied)
+            .take(4)
+            .count();
+
+        match state {
+            State::Empty if occupied == 0 => State::Occupied,
+            State::Occupied if occupied >= 4 => State::Empty,
+            state => state,
+        }
+    }
+
+    /// Same as `new_state_part_one` but looking at the first seat in sight on
+    /// every direction, instead of only the adjacent ones.
+    fn new_state_part_two(&self, x: usize, y: usize) -> State {
+        let state = self.map[(x, y)];
+        if let State::Floor = state {
+            return State::Floor;
+        }
+
+        let occupied = NEIGHBOURS_8
+            .iter()
+            .filter(|&&dir| {
+                // Keep searching in the sight of view while we see floor.
+                let seat = self
+                    .map
+                    .ray(x as i64, y as i64, dir)
+                    .map(|(_, state)| *state)
+                    .find(|state| *state != State::Floor);
+                seat == Some(State::Occupied)
+            })
+            .take(5)
+            .count();
+
+        match state {
+            State::Empty if occupied == 0 => State::Occupied,
+            State::Occupied if occupied >= 5 => State::Empty,
+            state => state,
+        }
+    }
+}
+
+/// Day 11: Seating System.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Layout;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Layout::parse(input)
+    }
+
+    /// Count occupied seats once the layout stabilizes looking only at adjacent seats.
+    fn part1(layout: &Self::Input) -> AnyResult<usize> {
+        let mut layout = layout.clone();
+        while layout.step(NewStateOp::PartOne) {}
+        Ok(layout.occupied())
+    }
+
+    /// Count occupied seats once the layout stabilizes looking at the first seat
+    /// in sight on every direction.
+    fn part2(layout: &Self::Input) -> AnyResult<usize> {
+        let mut layout = layout.clone();
+        while layout.step(NewStateOp::PartTwo) {}
+        Ok(layout.occupied())
+    }
+}
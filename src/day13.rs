@@ -0,0 +1,113 @@
+use anyhow::{anyhow, Result as AnyResult};
+
+use crate::Solution;
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    earliest_ts: usize,
+    bus_ids: Vec<usize>,
+}
+
+impl Notes {
+    pub fn parse(input: &str) -> AnyResult<Self> {
+        let mut lines = input.lines();
+
+        let earliest_ts = lines.next().ok_or_else(|| anyhow!("Empty file"))?;
+        let earliest_ts = earliest_ts.parse()?;
+
+        let bus_ids = lines
+            .next()
+            .ok_or_else(|| anyhow!("Empty file"))?
+            .split(',')
+            .filter_map(|s| s.parse().ok())
+            .collect();
+
+        Ok(Self {
+            earliest_ts,
+            bus_ids,
+        })
+    }
+
+    pub fn best_bus(&self) -> Option<(usize, usize)> {
+        let mut best_bus = None;
+        let mut best_wait_time = None;
+
+        for bus_id in &self.bus_ids {
+            let factor = (self.earliest_ts / bus_id) + 1;
+            let wait_time = (factor * bus_id) - self.earliest_ts;
+
+            let best_bus = best_bus.get_or_insert(bus_id);
+            let best_wait_time = best_wait_time.get_or_insert(wait_time);
+
+            if *best_wait_time > wait_time {
+                *best_bus = bus_id;
+                *best_wait_time = wait_time;
+            }
+        }
+
+        match (best_bus, best_wait_time) {
+            (Some(best_bus), Some(best_wait_time)) => Some((*best_bus, best_wait_time)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Departures {
+    bus_ids: Vec<(usize, usize)>,
+}
+
+impl Departures {
+    pub fn parse(input: &str) -> AnyResult<Self> {
+        let bus_ids = input
+            .lines()
+            .nth(1)
+            .ok_or_else(|| anyhow!("Empty file"))?
+            .split(',')
+            .enumerate()
+            .filter_map(|(i, s)| s.parse().ok().map(|n| (i, n)))
+            .collect();
+
+        Ok(Self { bus_ids })
+    }
+
+    pub fn solve(&self) -> Option<usize> {
+        let (remainders, modulis): (Vec<i64>, Vec<i64>) = self
+            .bus_ids
+            .iter()
+            .copied()
+            .map(|(offset, id)| ((id as i64 - offset as i64) % id as i64, id as i64))
+            .unzip();
+
+        ring_algorithm::chinese_remainder_theorem(&remainders, &modulis).map(|v| v as usize)
+    }
+}
+
+/// Day 13: Shuttle Search.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Notes, Departures);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Parse both the notes and the departures from the same input.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Ok((Notes::parse(input)?, Departures::parse(input)?))
+    }
+
+    /// Earliest bus we can take multiplied by the minutes we have to wait for it.
+    fn part1((notes, _): &Self::Input) -> AnyResult<usize> {
+        notes
+            .best_bus()
+            .map(|(bus, wait)| bus * wait)
+            .ok_or_else(|| anyhow!("No buses"))
+    }
+
+    /// Earliest timestamp such that all buses depart at their offsets.
+    fn part2((_, departures): &Self::Input) -> AnyResult<usize> {
+        departures
+            .solve()
+            .ok_or_else(|| anyhow!("No timestamp satisfies the offsets"))
+    }
+}
@@ -0,0 +1,154 @@
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, bail, Context, Result as AnyResult};
+
+use crate::Solution;
+
+/// Numbers the game can remember, so the turns it can be played and the
+/// largest starting number. The table takes 4 bytes per number, 256 MiB in
+/// total.
+pub const MAX_MEMORY: usize = 1 << 26;
+
+/// Memory game where each number spoken after the starting ones is how many
+/// turns apart the previous number was spoken the last two times, or 0 if
+/// it was spoken for the first time.
+#[derive(Debug, Clone)]
+pub struct Game {
+    /// Turn (starting at 1) in which each number was last spoken, indexed by
+    /// number, 0 if it wasn't spoken yet. The last number spoken isn't stored
+    /// until its turn is over.
+    last_seen: Vec<u32>,
+    /// Numbers spoken on the first turns.
+    starting: Vec<u32>,
+    turn: u32,
+    last_num: u32,
+}
+
+impl Game {
+    /// Start a game by speaking `starting_numbers`, fails if there are none
+    /// or if they don't fit the memory, see `MAX_MEMORY`.
+    pub fn new(starting_numbers: &[usize]) -> AnyResult<Self> {
+        let to_u32 = |num: usize| {
+            u32::try_from(num)
+                .ok()
+                .filter(|num| (*num as usize) < MAX_MEMORY)
+                .ok_or_else(|| anyhow!("Starting number too big: {}", num))
+        };
+
+        let numbers = starting_numbers
+            .iter()
+            .map(|num| to_u32(*num))
+            .collect::<AnyResult<Vec<_>>>()?;
+
+        let (last_num, previous) = numbers
+            .split_last()
+            .ok_or_else(|| anyhow!("No starting numbers"))?;
+
+        let turn = u32::try_from(starting_numbers.len())
+            .ok()
+            .filter(|turn| (*turn as usize) < MAX_MEMORY)
+            .ok_or_else(|| anyhow!("Too many starting numbers"))?;
+
+        let max = numbers.iter().copied().max().unwrap_or(0) as usize;
+        let mut last_seen = vec![0; max.max(numbers.len()) + 1];
+        for (idx, num) in previous.iter().enumerate() {
+            last_seen[*num as usize] = idx as u32 + 1;
+        }
+
+        Ok(Self {
+            last_seen,
+            turn,
+            last_num: *last_num,
+            starting: numbers,
+        })
+    }
+
+    /// Number of turns played so far.
+    pub fn turn(&self) -> usize {
+        self.turn as usize
+    }
+
+    /// Last number spoken.
+    pub fn last_num(&self) -> usize {
+        self.last_num as usize
+    }
+
+    /// Keep playing until turn `until` (starting at 1) and return the number
+    /// spoken on it, fails past `MAX_MEMORY` turns. Turns of the starting
+    /// numbers can always be asked for, other turns must not be played yet.
+    pub fn play_until(&mut self, until: usize) -> AnyResult<usize> {
+        if until == 0 {
+            bail!("Turns start at 1");
+        } else if until > MAX_MEMORY {
+            bail!("Turn too big: {}", until);
+        } else if let Some(num) = self.starting.get(until - 1) {
+            return Ok(*num as usize);
+        }
+
+        let until = until as u32;
+        if until < self.turn {
+            bail!("Turn {} was already played, now at {}", until, self.turn);
+        }
+
+        // A number spoken on a turn is always smaller than the turn.
+        if self.last_seen.len() < until as usize {
+            self.last_seen.resize(until as usize, 0);
+        }
+
+        let mut last_num = self.last_num;
+        for turn in self.turn..until {
+            let seen = std::mem::replace(&mut self.last_seen[last_num as usize], turn);
+            last_num = if seen == 0 { 0 } else { turn - seen };
+        }
+
+        self.turn = until;
+        self.last_num = last_num;
+        Ok(self.last_num())
+    }
+}
+
+/// Parse a comma separated list of starting numbers, e.g. `0,3,6`.
+pub fn parse_starting_numbers(input: &str) -> AnyResult<Vec<usize>> {
+    let input = input.trim();
+    if input.is_empty() {
+        bail!("No starting numbers");
+    }
+
+    input
+        .split(',')
+        .map(|num| {
+            let num = num.trim();
+            num.parse()
+                .with_context(|| format!("Invalid starting number: {:?}", num))
+        })
+        .collect()
+}
+
+/// Number spoken on turn `turn` (starting at 1) of a game started with
+/// `starting_numbers`.
+pub fn spoken_at(starting_numbers: &[usize], turn: usize) -> AnyResult<usize> {
+    Game::new(starting_numbers)?.play_until(turn)
+}
+
+/// Day 15: Rambunctious Recitation.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        parse_starting_numbers(input)
+    }
+
+    /// Number spoken on turn 2020.
+    fn part1(starting_numbers: &Self::Input) -> AnyResult<usize> {
+        spoken_at(starting_numbers, 2020)
+    }
+
+    /// Number spoken on turn 30000000.
+    fn part2(starting_numbers: &Self::Input) -> AnyResult<usize> {
+        spoken_at(starting_numbers, 30_000_000)
+    }
+}
@@ -0,0 +1,596 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Error, Result as AnyResult};
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{parse_lines, Solution};
+
+/// Policy written before a password, `<first>-<second> <letter>`. The first
+/// interpretation reads the numbers as the range of times `letter` must
+/// appear, the second one as positions (starting at 1) of the password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+}
+
+impl FromStr for PasswordPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numbers, letter) = s
+            .split_once(' ')
+            .ok_or_else(|| anyhow!("Missing policy letter"))?;
+        let (first, second) = numbers
+            .split_once('-')
+            .ok_or_else(|| anyhow!("Expected a range, got {:?}", numbers))?;
+
+        let parse_number = |num: &str| {
+            num.parse::<usize>()
+                .with_context(|| format!("Invalid number: {:?}", num))
+        };
+
+        let first = parse_number(first)?;
+        let second = parse_number(second)?;
+        if first == 0 || second == 0 {
+            bail!("Positions start at 1, got {}-{}", first, second);
+        }
+
+        if first > second {
+            bail!("Invalid range: {}-{}", first, second);
+        }
+
+        let mut chars = letter.chars();
+        let letter = match (chars.next(), chars.next()) {
+            (Some(letter), None) => letter,
+            _ => bail!("Expected a single letter, got {:?}", letter),
+        };
+
+        Ok(Self {
+            first,
+            second,
+            letter,
+        })
+    }
+}
+
+/// Line of the password database, `<policy>: <password>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordEntry {
+    pub policy: PasswordPolicy,
+    pub password: String,
+}
+
+impl FromStr for PasswordEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (policy, password) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Missing password"))?;
+
+        let password = password.trim();
+        if password.is_empty() {
+            bail!("Missing password");
+        }
+
+        Ok(Self {
+            policy: policy.trim().parse()?,
+            password: password.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for PasswordPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.first, self.second, self.letter)
+    }
+}
+
+impl fmt::Display for PasswordEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.policy, self.password)
+    }
+}
+
+impl PasswordEntry {
+    /// Positions of the policy (starting at 1), twice if they're equal so a
+    /// letter there counts for both.
+    fn positions(&self) -> Vec<usize> {
+        vec![self.policy.first, self.policy.second]
+    }
+
+    /// Positions of the policy that hold its letter.
+    fn positions_with_letter(&self) -> Vec<usize> {
+        let letter = Some(self.policy.letter);
+        self.positions()
+            .into_iter()
+            .filter(|pos| self.password.chars().nth(pos - 1) == letter)
+            .collect()
+    }
+}
+
+/// Describe `positions`, e.g. "positions 1 and 3", with `both` before the
+/// last one if there are two of them.
+fn describe_positions(positions: &[usize], both: bool) -> String {
+    match positions {
+        [pos] => format!("position {}", pos),
+        [first, second] if both => format!("positions {} and {} both", first, second),
+        _ => format!("positions {}", positions.iter().join(" and ")),
+    }
+}
+
+/// Rule a password database entry has to follow, displayed as the name it's
+/// selected with, see `parse_policy`.
+pub trait Policy: fmt::Display {
+    /// Reason why `entry` breaks the policy, `None` if it follows it.
+    fn violation(&self, entry: &PasswordEntry) -> Option<String>;
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        self.violation(entry).is_none()
+    }
+}
+
+/// First interpretation: the letter must appear a number of times within
+/// the range.
+#[derive(Debug, Clone, Copy)]
+pub struct RangeCount;
+
+impl Policy for RangeCount {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let PasswordPolicy {
+            first,
+            second,
+            letter,
+        } = entry.policy;
+
+        let letter_count = entry.password.chars().filter(|c| *c == letter).count();
+        (!(first..=second).contains(&letter_count)).then(|| {
+            format!(
+                "letter {:?} appears {} times, allowed {}-{}",
+                letter, letter_count, first, second
+            )
+        })
+    }
+}
+
+impl fmt::Display for RangeCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "range")
+    }
+}
+
+/// Second interpretation: exactly one of the positions must hold the letter,
+/// and both positions must be part of the password.
+#[derive(Debug, Clone, Copy)]
+pub struct ExactlyOne;
+
+impl Policy for ExactlyOne {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let PasswordPolicy { second, letter, .. } = entry.policy;
+        let len = entry.password.chars().count();
+        if second > len {
+            return Some(format!(
+                "position {} is past the end of the password",
+                second
+            ));
+        }
+
+        match entry.positions_with_letter().as_slice() {
+            [_] => None,
+            [] => Some(format!(
+                "no letter {:?} in {}",
+                letter,
+                describe_positions(&entry.positions(), false)
+            )),
+            positions => Some(format!(
+                "{} contain {:?}",
+                describe_positions(positions, true),
+                letter
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ExactlyOne {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one-of")
+    }
+}
+
+/// At least `n` of the positions must hold the letter.
+#[derive(Debug, Clone, Copy)]
+pub struct AtLeast(pub usize);
+
+impl Policy for AtLeast {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let found = entry.positions_with_letter().len();
+        (found < self.0).then(|| {
+            format!(
+                "{} of {} contain {:?}, need {}",
+                found,
+                describe_positions(&entry.positions(), false),
+                entry.policy.letter,
+                self.0
+            )
+        })
+    }
+}
+
+impl fmt::Display for AtLeast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at-least:{}", self.0)
+    }
+}
+
+/// None of the positions may hold the letter.
+#[derive(Debug, Clone, Copy)]
+pub struct NoneOf;
+
+impl Policy for NoneOf {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let found = entry.positions_with_letter();
+        let contain = if found.len() == 1 {
+            "contains"
+        } else {
+            "contain"
+        };
+        (!found.is_empty()).then(|| {
+            format!(
+                "{} {} {:?}",
+                describe_positions(&found, true),
+                contain,
+                entry.policy.letter
+            )
+        })
+    }
+}
+
+impl fmt::Display for NoneOf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "none-of")
+    }
+}
+
+/// The password must match a regex, whatever the policy of the entry says.
+#[derive(Debug, Clone)]
+pub struct Matches(pub Regex);
+
+impl Policy for Matches {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        (!self.0.is_match(&entry.password)).then(|| format!("password doesn't match /{}/", self.0))
+    }
+}
+
+/// Quoted if needed, so it can be parsed back with `parse_policy`.
+impl fmt::Display for Matches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pattern = self.0.as_str();
+        if pattern.contains(" and ") || pattern.contains(" or ") || pattern.contains('"') {
+            write!(f, "regex:\"{}\"", pattern.replace('"', "\\\""))
+        } else {
+            write!(f, "regex:{}", pattern)
+        }
+    }
+}
+
+/// Every policy must hold.
+pub struct All(pub Vec<Box<dyn Policy>>);
+
+impl Policy for All {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let violations: Vec<_> = self
+            .0
+            .iter()
+            .filter_map(|policy| policy.violation(entry))
+            .collect();
+
+        (!violations.is_empty()).then(|| violations.join("; "))
+    }
+}
+
+impl fmt::Display for All {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().join(" and "))
+    }
+}
+
+/// At least one of the policies must hold.
+pub struct Any(pub Vec<Box<dyn Policy>>);
+
+impl Policy for Any {
+    fn violation(&self, entry: &PasswordEntry) -> Option<String> {
+        let violations = self
+            .0
+            .iter()
+            .map(|policy| policy.violation(entry))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(violations.join("; "))
+    }
+}
+
+impl fmt::Display for Any {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().join(" or "))
+    }
+}
+
+/// Split `spec` on every `sep` that isn't inside double quotes, where `\"`
+/// stands for a quote.
+fn split_unquoted<'a>(spec: &'a str, sep: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut chars = spec.char_indices();
+    while let Some((idx, chr)) = chars.next() {
+        match chr {
+            '"' => quoted = !quoted,
+            '\\' if quoted && spec[idx + 1..].starts_with('"') => {
+                chars.next();
+            }
+            _ if !quoted && spec[idx..].starts_with(sep) => {
+                parts.push(&spec[start..idx]);
+                start = idx + sep.len();
+                for _ in 1..sep.len() {
+                    chars.next();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&spec[start..]);
+    parts
+}
+
+/// Build a policy from its name:
+/// - `range`: `RangeCount`
+/// - `one-of`: `ExactlyOne`
+/// - `at-least:<n>`: `AtLeast`
+/// - `none-of`: `NoneOf`
+/// - `regex:<pattern>`: `Matches`
+///
+/// Policies can be combined with ` and ` and ` or `, where `and` binds
+/// tighter, e.g. `one-of and regex:^[a-z]+$ or none-of`. A pattern can be
+/// written between double quotes to hold those words or quotes, with `\"`
+/// for a quote, e.g. `regex:"^(cat or dog)$"`.
+pub fn parse_policy(spec: &str) -> AnyResult<Box<dyn Policy>> {
+    fn parse_all(spec: &str) -> AnyResult<Box<dyn Policy>> {
+        let mut policies = split_unquoted(spec, " and ")
+            .into_iter()
+            .map(parse_single)
+            .collect::<AnyResult<Vec<_>>>()?;
+        if policies.len() == 1 {
+            Ok(policies.remove(0))
+        } else {
+            Ok(Box::new(All(policies)))
+        }
+    }
+
+    fn parse_single(spec: &str) -> AnyResult<Box<dyn Policy>> {
+        let spec = spec.trim();
+        let (name, arg) = match spec.split_once(':') {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (spec, None),
+        };
+
+        Ok(match (name, arg) {
+            ("range", None) => Box::new(RangeCount),
+            ("one-of", None) => Box::new(ExactlyOne),
+            ("none-of", None) => Box::new(NoneOf),
+            ("at-least", Some(n)) => Box::new(AtLeast(
+                n.parse()
+                    .with_context(|| format!("Invalid count: {:?}", n))?,
+            )),
+            ("regex", Some(pattern)) => Box::new(Matches(Regex::new(&unquote(pattern)?)?)),
+            _ => bail!("Unknown policy: {:?}", spec),
+        })
+    }
+
+    fn unquote(pattern: &str) -> AnyResult<String> {
+        let mut chars = match pattern.strip_prefix('"') {
+            Some(inner) => inner.chars(),
+            None if pattern.contains('"') => {
+                bail!("Patterns with quotes must be quoted: {:?}", pattern)
+            }
+            None => return Ok(pattern.to_owned()),
+        };
+
+        let mut unquoted = String::new();
+        while let Some(chr) = chars.next() {
+            match chr {
+                '\\' if chars.as_str().starts_with('"') => {
+                    unquoted.push('"');
+                    chars.next();
+                }
+                '"' if chars.as_str().is_empty() => return Ok(unquoted),
+                '"' => bail!("Unexpected text after the quotes: {:?}", pattern),
+                chr => unquoted.push(chr),
+            }
+        }
+
+        bail!("Unterminated quotes: {:?}", pattern)
+    }
+
+    let mut policies = split_unquoted(spec, " or ")
+        .into_iter()
+        .map(parse_all)
+        .collect::<AnyResult<Vec<_>>>()?;
+    if policies.len() == 1 {
+        Ok(policies.remove(0))
+    } else {
+        Ok(Box::new(Any(policies)))
+    }
+}
+
+/// Count the entries that are valid under `policy`.
+pub fn count_valid(entries: &[PasswordEntry], policy: &dyn Policy) -> usize {
+    entries
+        .iter()
+        .filter(|entry| policy.is_valid(entry))
+        .count()
+}
+
+/// Verdicts of every entry of a password database under several policies.
+pub struct Audit<'a> {
+    entries: &'a [PasswordEntry],
+    policies: &'a [Box<dyn Policy>],
+    /// For each entry the violation of each policy, if any.
+    violations: Vec<Vec<Option<String>>>,
+}
+
+impl<'a> Audit<'a> {
+    pub fn new(entries: &'a [PasswordEntry], policies: &'a [Box<dyn Policy>]) -> Self {
+        let violations = entries
+            .iter()
+            .map(|entry| {
+                policies
+                    .iter()
+                    .map(|policy| policy.violation(entry))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            entries,
+            policies,
+            violations,
+        }
+    }
+
+    /// One JSON object per entry, with its line number (starting at 1), its
+    /// policy, its password and the verdict under each policy, e.g.
+    /// `{"line":2,"policy":"1-3 b","password":"cdefg","verdicts":[{"policy":"range","valid":false,"reason":"..."}]}`.
+    pub fn json_lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.entries.iter().zip(&self.violations).enumerate().map(
+            move |(idx, (entry, violations))| {
+                let verdicts = self
+                    .policies
+                    .iter()
+                    .zip(violations)
+                    .map(|(policy, violation)| {
+                        let policy = json_string(&policy.to_string());
+                        match violation {
+                            None => format!("{{\"policy\":{},\"valid\":true}}", policy),
+                            Some(reason) => format!(
+                                "{{\"policy\":{},\"valid\":false,\"reason\":{}}}",
+                                policy,
+                                json_string(reason)
+                            ),
+                        }
+                    });
+
+                format!(
+                    "{{\"line\":{},\"policy\":{},\"password\":{},\"verdicts\":[{}]}}",
+                    idx + 1,
+                    json_string(&entry.policy.to_string()),
+                    json_string(&entry.password),
+                    verdicts.format(",")
+                )
+            },
+        )
+    }
+}
+
+/// Table with a row per entry and a column per policy, failures are
+/// explained in the last column.
+impl fmt::Display for Audit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut header = vec![
+            "line".to_owned(),
+            "policy".to_owned(),
+            "password".to_owned(),
+        ];
+        header.extend(self.policies.iter().map(|policy| policy.to_string()));
+        header.push("reason".to_owned());
+
+        let rows = self.entries.iter().zip(&self.violations).enumerate();
+        let rows = rows.map(|(idx, (entry, violations))| {
+            let mut row = vec![
+                (idx + 1).to_string(),
+                entry.policy.to_string(),
+                entry.password.clone(),
+            ];
+
+            row.extend(violations.iter().map(|violation| match violation {
+                None => "valid".to_owned(),
+                Some(_) => "invalid".to_owned(),
+            }));
+
+            let reasons = self.policies.iter().zip(violations);
+            let reasons = reasons.filter_map(|(policy, violation)| {
+                violation
+                    .as_ref()
+                    .map(|reason| format!("{}: {}", policy, reason))
+            });
+            row.push(reasons.format("; ").to_string());
+            row
+        });
+
+        let rows: Vec<_> = std::iter::once(header).chain(rows).collect();
+        let mut widths = vec![0; rows[0].len()];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in &rows {
+            let cells = row.iter().zip(&widths);
+            let line = cells
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Quote and escape `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for chr in s.chars() {
+        match chr {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            chr if chr.is_control() => quoted.push_str(&format!("\\u{:04x}", chr as u32)),
+            chr => quoted.push(chr),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Day 2: Password Philosophy.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<PasswordEntry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Parse an entry from every line.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        parse_lines(input)
+    }
+
+    /// Count all the passwords that are valid given the first interpretation.
+    fn part1(entries: &Self::Input) -> AnyResult<usize> {
+        Ok(count_valid(entries, &RangeCount))
+    }
+
+    /// Count all the passwords that are valid given the second interpretation.
+    fn part2(entries: &Self::Input) -> AnyResult<usize> {
+        Ok(count_valid(entries, &ExactlyOne))
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Error, Result as AnyResult};
+
+use crate::{Grid, Solution};
+
+/// Steps taken to the right and down on each move of the toboggan, written
+/// as `<right>,<down>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub const fn new(right: usize, down: usize) -> Self {
+        Self { right, down }
+    }
+}
+
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (right, down) = s
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Expected <right>,<down>, got {:?}", s))?;
+
+        let parse_steps = |steps: &str| {
+            steps
+                .trim()
+                .parse::<usize>()
+                .with_context(|| format!("Invalid slope {:?}", s))
+        };
+
+        let slope = Self::new(parse_steps(right)?, parse_steps(down)?);
+        if slope.down == 0 {
+            bail!("Invalid slope {:?}, it must go down", s);
+        }
+
+        Ok(slope)
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.right, self.down)
+    }
+}
+
+/// Slopes checked in the second part.
+pub const PUZZLE_SLOPES: [Slope; 5] = [
+    Slope::new(1, 1),
+    Slope::new(3, 1),
+    Slope::new(5, 1),
+    Slope::new(7, 1),
+    Slope::new(1, 2),
+];
+
+/// What `Map::best_slope` looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    FewestTrees,
+    MostTrees,
+}
+
+/// Hold the map as a grid that repeats itself to the right.
+pub struct Map {
+    map: Grid<char>,
+}
+
+impl Map {
+    /// Build the map from its textual representation.
+    pub fn build_map(input: &str) -> AnyResult<Self> {
+        let map = Grid::parse(input, Ok)?.with_wrap_x(true);
+        Ok(Self { map })
+    }
+
+    pub fn width(&self) -> usize {
+        self.map.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    /// Square at `(x, y)`, where `x` can be past the width of the map.
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        self.map.get(x as i64, y as i64).copied()
+    }
+
+    /// Squares visited moving along `slope` from the top left corner, the
+    /// corner excluded, until we reach the bottom part of the map. `x` keeps
+    /// growing past the width of the map as it repeats itself. A slope that
+    /// doesn't go down never reaches the bottom, so it visits nothing.
+    pub fn path(&self, slope: Slope) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        let step = (slope.right as i64, slope.down as i64);
+        self.map
+            .ray(0, 0, step)
+            .take_while(move |_| slope.down > 0)
+            .map(|((x, y), square)| ((x as usize, y as usize), *square))
+    }
+
+    /// Traverse the map moving along `slope` repeteadly until we reach the
+    /// bottom part of the map. Returns the number of trees we found.
+    pub fn traverse(&self, slope: Slope) -> usize {
+        self.path(slope)
+            .filter(|(_, square)| *square == '#')
+            .count()
+    }
+
+    /// Start drawing the map, see `Renderer`.
+    pub fn render(&self) -> Renderer<'_> {
+        Renderer::new(self)
+    }
+
+    /// Number of trees found along each slope and their product, `None` if
+    /// it overflows.
+    pub fn survey(&self, slopes: &[Slope]) -> (Vec<usize>, Option<usize>) {
+        let trees: Vec<_> = slopes.iter().map(|slope| self.traverse(*slope)).collect();
+        let product = trees
+            .iter()
+            .try_fold(1usize, |acc, trees| acc.checked_mul(*trees));
+        (trees, product)
+    }
+
+    /// Find the slope within `rights` and `downs` that meets `goal` along with
+    /// its number of trees, ties are won by the smallest `right` and then the
+    /// smallest `down`. A `down` of 0 is skipped.
+    pub fn best_slope(
+        &self,
+        rights: RangeInclusive<usize>,
+        downs: RangeInclusive<usize>,
+        goal: Goal,
+    ) -> Option<(Slope, usize)> {
+        let slopes = rights.flat_map(|right| {
+            downs
+                .clone()
+                .filter(|down| *down > 0)
+                .map(move |down| Slope::new(right, down))
+        });
+
+        let counts = slopes.map(|slope| (slope, self.traverse(slope)));
+        counts.fold(None, |best, (slope, trees)| match best {
+            Some((_, best_trees)) if goal == Goal::FewestTrees && trees >= best_trees => best,
+            Some((_, best_trees)) if goal == Goal::MostTrees && trees <= best_trees => best,
+            _ => Some((slope, trees)),
+        })
+    }
+}
+
+/// ANSI colours given to the slopes drawn, in order.
+const COLOURS: [u8; 6] = [31, 32, 33, 34, 35, 36];
+
+/// Draws the repeated map with the squares visited along some slopes marked
+/// as in the puzzle, `O` for open squares and `X` for trees. Squares visited
+/// by several slopes are marked for the first one.
+#[derive(Clone)]
+pub struct Renderer<'a> {
+    map: &'a Map,
+    slopes: Vec<Slope>,
+    colour: bool,
+    columns: Option<Range<usize>>,
+    rows: Option<Range<usize>>,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(map: &'a Map) -> Self {
+        Self {
+            map,
+            slopes: Vec::new(),
+            colour: false,
+            columns: None,
+            rows: None,
+        }
+    }
+
+    /// Draw the path along `slope` too.
+    pub fn slope(mut self, slope: Slope) -> Self {
+        self.slopes.push(slope);
+        self
+    }
+
+    /// Paint the marks of each slope with a different ANSI colour.
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    /// Draw these columns of the repeated map, by default only the map's own
+    /// width, without repeating it.
+    pub fn columns(mut self, columns: Range<usize>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Only draw these rows, by default all of them.
+    pub fn rows(mut self, rows: Range<usize>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+impl fmt::Display for Renderer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.map;
+        let rows = self.rows.clone().unwrap_or(0..map.height());
+        let rows = rows.start..rows.end.min(map.height());
+
+        let mut marks = HashMap::new();
+        for (idx, slope) in self.slopes.iter().enumerate() {
+            let path = map.path(*slope).filter(|((_, y), _)| rows.contains(y));
+            for (pos, _) in path {
+                marks.entry(pos).or_insert(idx);
+            }
+        }
+
+        let columns = self.columns.clone().unwrap_or(0..map.width());
+
+        for y in rows {
+            for x in columns.clone() {
+                let square = map.get(x, y).unwrap_or(' ');
+                let mark = match square {
+                    '#' => 'X',
+                    _ => 'O',
+                };
+
+                match marks.get(&(x, y)) {
+                    None => write!(f, "{}", square)?,
+                    Some(idx) if self.colour => {
+                        let colour = COLOURS[idx % COLOURS.len()];
+                        write!(f, "\x1b[1;{}m{}\x1b[0m", colour, mark)?
+                    }
+                    Some(_) => write!(f, "{}", mark)?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Day 3: Toboggan Trajectory.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Map::build_map(input)
+    }
+
+    /// Initial traverse.
+    fn part1(map: &Self::Input) -> AnyResult<usize> {
+        Ok(map.traverse(Slope::new(3, 1)))
+    }
+
+    /// Traverse with different configurations and return the product.
+    fn part2(map: &Self::Input) -> AnyResult<usize> {
+        map.survey(&PUZZLE_SLOPES)
+            .1
+            .ok_or_else(|| anyhow!("Product overflows"))
+    }
+}
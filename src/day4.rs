@@ -0,0 +1,609 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Error, Result as AnyResult};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{numbered_groups, read_input, Solution};
+
+/// Schema with the rules of the puzzle.
+const PUZZLE_SCHEMA: &str = include_str!("../files/day4/schema.txt");
+
+/// Parse an inclusive range written as `<min>-<max>`.
+fn parse_range(range: &str) -> AnyResult<(u64, u64)> {
+    let (min, max) = range
+        .split_once('-')
+        .ok_or_else(|| anyhow!("Expected <min>-<max>, got {:?}", range))?;
+
+    let min = min
+        .parse()
+        .with_context(|| format!("Invalid min: {:?}", min))?;
+    let max = max
+        .parse()
+        .with_context(|| format!("Invalid max: {:?}", max))?;
+    if min > max {
+        bail!("Invalid range: {}-{}", min, max);
+    }
+
+    Ok((min, max))
+}
+
+/// Rule the value of a field has to follow.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Any,
+    /// Integer within the range.
+    Range(u64, u64),
+    /// Integer followed by one of the units, within the range of that unit.
+    Units(Vec<(String, u64, u64)>),
+    /// Value matching the whole pattern.
+    Pattern(Regex),
+    /// One of the values.
+    OneOf(Vec<String>),
+}
+
+/// List `values` as `a, b or c`.
+fn one_of<T: AsRef<str>>(values: &[T]) -> String {
+    match values.split_last() {
+        Some((last, [])) => last.as_ref().to_owned(),
+        Some((last, rest)) => {
+            let rest: Vec<_> = rest.iter().map(AsRef::as_ref).collect();
+            format!("{} or {}", rest.join(", "), last.as_ref())
+        }
+        None => String::new(),
+    }
+}
+
+impl Rule {
+    pub fn matches(&self, val: &str) -> bool {
+        self.check(val).is_ok()
+    }
+
+    /// Check that `val` follows the rule, failing with the reason why not.
+    pub fn check(&self, val: &str) -> AnyResult<()> {
+        let in_range = |num: &str, min, max| {
+            let num = num.parse::<u64>().context("Not a number")?;
+            if !(min..=max).contains(&num) {
+                bail!("Must be within {} and {}", min, max);
+            }
+
+            Ok(())
+        };
+
+        match self {
+            Rule::Any => Ok(()),
+            Rule::Range(min, max) => in_range(val, *min, *max),
+            Rule::Units(units) => {
+                let unit = units.iter().find_map(|(unit, min, max)| {
+                    val.strip_suffix(unit.as_str()).map(|num| (num, min, max))
+                });
+
+                match unit {
+                    Some((num, min, max)) => in_range(num, *min, *max),
+                    None => {
+                        let units: Vec<_> = units.iter().map(|(unit, ..)| unit).collect();
+                        bail!("Missing unit, expected {}", one_of(&units))
+                    }
+                }
+            }
+
+            Rule::Pattern(regex) if regex.is_match(val) => Ok(()),
+            Rule::Pattern(regex) => {
+                // Skip the anchors added when parsing the rule.
+                let pattern = regex.as_str();
+                bail!("Must match {}", &pattern[4..pattern.len() - 2])
+            }
+
+            Rule::OneOf(values) if values.iter().any(|value| value == val) => Ok(()),
+            Rule::OneOf(values) => bail!("Expected one of {}", one_of(values)),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parse a rule written as in a schema file, e.g. `range 1920-2002`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, args) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        let args = args.trim();
+
+        let rule = match name {
+            "any" => Rule::Any,
+            "range" => {
+                let (min, max) = parse_range(args)?;
+                Rule::Range(min, max)
+            }
+
+            "units" => {
+                let units = args.split_whitespace().map(|range| {
+                    let split = range
+                        .rfind(|c: char| c.is_ascii_digit())
+                        .map_or(0, |idx| idx + 1);
+                    let (range, unit) = range.split_at(split);
+                    if unit.is_empty() {
+                        bail!("Missing unit after {:?}", range);
+                    }
+
+                    let (min, max) = parse_range(range)?;
+                    Ok((unit.to_owned(), min, max))
+                });
+
+                Rule::Units(units.collect::<AnyResult<_>>()?)
+            }
+
+            "regex" => Rule::Pattern(Regex::new(&format!("^(?:{})$", args))?),
+            "enum" => Rule::OneOf(args.split_whitespace().map(String::from).collect()),
+            _ => bail!("Unknown rule: {:?}", name),
+        };
+
+        match &rule {
+            Rule::Units(units) if units.is_empty() => bail!("No units given"),
+            Rule::OneOf(values) if values.is_empty() => bail!("No values given"),
+            _ => Ok(rule),
+        }
+    }
+}
+
+/// Field a document can have.
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub required: bool,
+    pub rule: Rule,
+}
+
+/// Fields a document is made of, along with the rule their values have to
+/// follow. Documents with fields that aren't part of the schema are invalid.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    fields: HashMap<String, Field>,
+}
+
+impl Schema {
+    /// Schema with the rules of the puzzle, see `files/day4/schema.txt`.
+    pub fn puzzle() -> Self {
+        PUZZLE_SCHEMA.parse().unwrap()
+    }
+
+    /// Load a schema from the file at `path`, see `Schema::from_str`.
+    pub fn load(path: impl AsRef<Path>) -> AnyResult<Self> {
+        let path = path.as_ref();
+        read_input(path)?
+            .parse()
+            .with_context(|| format!("Invalid schema {}", path.display()))
+    }
+
+    pub fn field(&self, key: &str) -> Option<&Field> {
+        self.fields.get(key)
+    }
+
+    /// Check whether `doc` has every required field and no unknown ones,
+    /// without looking at their values.
+    pub fn is_complete(&self, doc: &Document) -> bool {
+        let known = doc.data.keys().all(|key| self.fields.contains_key(key));
+        let required = self
+            .fields
+            .iter()
+            .filter(|(_, field)| field.required)
+            .all(|(key, _)| doc.data.contains_key(key));
+
+        known && required
+    }
+
+    /// Check whether `doc` is complete and every value follows its rule.
+    pub fn is_valid(&self, doc: &Document) -> bool {
+        self.is_complete(doc)
+            && doc
+                .data
+                .iter()
+                .all(|(key, val)| self.fields[key].rule.matches(val))
+    }
+}
+
+impl FromStr for Schema {
+    type Err = Error;
+
+    /// Parse a schema with one field per line, written as `<key>
+    /// required|optional <rule>`. Blank lines and lines starting with `#`
+    /// are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = HashMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parse_field = || -> AnyResult<(String, Field)> {
+                let mut parts = line.splitn(3, char::is_whitespace);
+                let key = parts.next().unwrap_or_default();
+                let required = match parts.next() {
+                    Some("required") => true,
+                    Some("optional") => false,
+                    _ => bail!("Expected required or optional"),
+                };
+
+                let rule = parts.next().unwrap_or_default().parse()?;
+                Ok((key.to_owned(), Field { required, rule }))
+            };
+
+            let (key, field) =
+                parse_field().with_context(|| format!("Line {}: {:?}", idx + 1, line))?;
+            if fields.insert(key.clone(), field).is_some() {
+                bail!("Line {}: field {} is repeated", idx + 1, key);
+            }
+        }
+
+        Ok(Self { fields })
+    }
+}
+
+/// Holds a map to store key value pairs.
+#[derive(Debug, Clone, Default)]
+pub struct Document {
+    data: HashMap<String, String>,
+}
+
+impl Document {
+    /// Build a document from the `key:val` pairs of a passport, see
+    /// `Batch::parse` for how bad pairs are handled.
+    pub fn parse(passport: &str) -> Self {
+        let mut doc = Self::default();
+        for line in passport.lines() {
+            doc.extend(line, |_| {});
+        }
+
+        doc
+    }
+
+    /// Add the `key:val` pairs of `line`, calling `report` with each problem
+    /// found along the way.
+    fn extend(&mut self, line: &str, mut report: impl FnMut(Problem)) {
+        for token in line.split_whitespace() {
+            match token.split_once(':') {
+                Some((key, val)) if !key.is_empty() => {
+                    if self.data.insert(key.to_owned(), val.to_owned()).is_some() {
+                        report(Problem::Duplicate(key.to_owned()));
+                    }
+                }
+
+                _ => report(Problem::Garbage(token.to_owned())),
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.data.get(key).map(String::as_str)
+    }
+
+    /// Iterate over the fields of the document in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.data
+            .iter()
+            .map(|(key, val)| (key.as_str(), val.as_str()))
+    }
+}
+
+/// Problem found in a line of a passport batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// Token that isn't a `key:val` pair, it's left out of the passport.
+    Garbage(String),
+    /// Key given more than once in a passport, the last value is kept.
+    Duplicate(String),
+}
+
+/// Problem found in a passport batch, along with where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Number of the line, starting at 1.
+    pub line: usize,
+    /// Number of the passport in the batch, starting at 1.
+    pub passport: usize,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}, passport {}: ", self.line, self.passport)?;
+        match &self.problem {
+            Problem::Garbage(token) => write!(f, "not a key:val pair: {:?}", token),
+            Problem::Duplicate(key) => write!(f, "repeated key: {}", key),
+        }
+    }
+}
+
+/// Passports of a batch file along with the problems found reading them.
+#[derive(Debug, Clone, Default)]
+pub struct Batch {
+    pub passports: Vec<Document>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Batch {
+    /// Read the passports of a batch, separated by blank lines. The fields
+    /// of a passport can be split in any number of lines and separated by
+    /// any whitespace, and the last passport doesn't need a trailing blank
+    /// line. Tokens that aren't `key:val` pairs and keys given more than once
+    /// are reported instead of failing.
+    pub fn parse(input: &str) -> Self {
+        let mut batch = Self::default();
+        for (idx, lines) in numbered_groups(input).enumerate() {
+            let mut doc = Document::default();
+            for (line, text) in lines {
+                doc.extend(text, |problem| {
+                    batch.diagnostics.push(Diagnostic {
+                        line,
+                        passport: idx + 1,
+                        problem,
+                    })
+                });
+            }
+
+            batch.passports.push(doc);
+        }
+
+        batch
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Height {
+    Cm(u32),
+    In(u32),
+}
+
+impl FromStr for Height {
+    type Err = Error;
+
+    /// Parse a height in cm or in, e.g. `183cm`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |num: &str| num.parse().context("Not a number");
+        if let Some(height) = s.strip_suffix("cm") {
+            Ok(Height::Cm(parse(height)?))
+        } else if let Some(height) = s.strip_suffix("in") {
+            Ok(Height::In(parse(height)?))
+        } else {
+            bail!("Missing unit, expected cm or in");
+        }
+    }
+}
+
+/// Colour written as `#rrggbb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexColor(pub u32);
+
+impl FromStr for HexColor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                Ok(Self(u32::from_str_radix(hex, 16)?))
+            }
+            _ => bail!("Expected # followed by 6 hexadecimal digits"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "amb" => EyeColor::Amber,
+            "blu" => EyeColor::Blue,
+            "brn" => EyeColor::Brown,
+            "gry" => EyeColor::Gray,
+            "grn" => EyeColor::Green,
+            "hzl" => EyeColor::Hazel,
+            "oth" => EyeColor::Other,
+            _ => bail!("Expected one of amb, blu, brn, gry, grn, hzl or oth"),
+        })
+    }
+}
+
+/// Passport id made of 9 digits, leading zeros included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassportId(String);
+
+impl PassportId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PassportId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 9 || !s.chars().all(|c| c.is_ascii_digit()) {
+            bail!("Expected 9 digits");
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+}
+
+/// Reason why a field of a passport is wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldError {
+    Missing(&'static str),
+    Invalid {
+        key: &'static str,
+        val: String,
+        reason: String,
+    },
+    Unknown(String),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::Missing(key) => write!(f, "{}: missing", key),
+            FieldError::Invalid { key, val, reason } => write!(f, "{}: {:?}: {}", key, val, reason),
+            FieldError::Unknown(key) => write!(f, "{}: unknown field", key),
+        }
+    }
+}
+
+/// Passport with every field parsed and checked against the rules of the
+/// puzzle, see `Schema::puzzle`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passport {
+    pub birth_year: u32,
+    pub issue_year: u32,
+    pub expiration_year: u32,
+    pub height: Height,
+    pub hair_color: HexColor,
+    pub eye_color: EyeColor,
+    pub passport_id: PassportId,
+    pub country_id: Option<String>,
+}
+
+/// Keys of the fields of a passport.
+const PASSPORT_KEYS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+/// Parse the field `key` of `doc` with `parse` once it follows the rule of
+/// the puzzle schema, recording in `errors` why it couldn't be done.
+fn parse_field<T>(
+    doc: &Document,
+    key: &'static str,
+    errors: &mut Vec<FieldError>,
+    parse: impl FnOnce(&str) -> AnyResult<T>,
+) -> Option<T> {
+    let val = match doc.get(key) {
+        Some(val) => val,
+        None => {
+            errors.push(FieldError::Missing(key));
+            return None;
+        }
+    };
+
+    lazy_static! {
+        static ref SCHEMA: Schema = Schema::puzzle();
+    }
+
+    let rule = &SCHEMA.fields[key].rule;
+    match rule.check(val).and_then(|_| parse(val)) {
+        Ok(parsed) => Some(parsed),
+        Err(err) => {
+            errors.push(FieldError::Invalid {
+                key,
+                val: val.to_owned(),
+                reason: format!("{:#}", err),
+            });
+            None
+        }
+    }
+}
+
+impl Passport {
+    /// Build a passport from the fields of `doc`, on failure returns an error
+    /// for each missing, invalid or unknown field, in the order the fields
+    /// are checked and with the unknown ones last.
+    pub fn new(doc: &Document) -> Result<Self, Vec<FieldError>> {
+        let mut errors = Vec::new();
+        let year = |val: &str| val.parse().context("Not a number");
+        let birth_year = parse_field(doc, "byr", &mut errors, year);
+        let issue_year = parse_field(doc, "iyr", &mut errors, year);
+        let expiration_year = parse_field(doc, "eyr", &mut errors, year);
+        let height = parse_field(doc, "hgt", &mut errors, str::parse);
+        let hair_color = parse_field(doc, "hcl", &mut errors, str::parse);
+        let eye_color = parse_field(doc, "ecl", &mut errors, str::parse);
+        let passport_id = parse_field(doc, "pid", &mut errors, str::parse);
+        let country_id = doc.get("cid").map(String::from);
+
+        let mut unknown: Vec<_> = doc
+            .iter()
+            .filter(|(key, _)| !PASSPORT_KEYS.contains(key))
+            .map(|(key, _)| key.to_owned())
+            .collect();
+        unknown.sort_unstable();
+        errors.extend(unknown.into_iter().map(FieldError::Unknown));
+
+        match (
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+        ) {
+            (Some(byr), Some(iyr), Some(eyr), Some(hgt), Some(hcl), Some(ecl), Some(pid))
+                if errors.is_empty() =>
+            {
+                Ok(Self {
+                    birth_year: byr,
+                    issue_year: iyr,
+                    expiration_year: eyr,
+                    height: hgt,
+                    hair_color: hcl,
+                    eye_color: ecl,
+                    passport_id: pid,
+                    country_id,
+                })
+            }
+
+            _ => Err(errors),
+        }
+    }
+}
+
+/// Count the passports in `batch` that have all the fields of `schema`.
+pub fn count_complete(batch: &Batch, schema: &Schema) -> usize {
+    batch
+        .passports
+        .iter()
+        .filter(|doc| schema.is_complete(doc))
+        .count()
+}
+
+/// Count the passports in `batch` that are valid under `schema`.
+pub fn count_valid(batch: &Batch, schema: &Schema) -> usize {
+    batch
+        .passports
+        .iter()
+        .filter(|doc| schema.is_valid(doc))
+        .count()
+}
+
+/// Day 4: Passport Processing.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Batch;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Split the batch file in passports.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Ok(Batch::parse(input))
+    }
+
+    /// Count how many documents are valid, part 1 without data validation.
+    fn part1(batch: &Self::Input) -> AnyResult<usize> {
+        Ok(count_complete(batch, &Schema::puzzle()))
+    }
+
+    /// Count how many documents are valid, part 2 with data validation.
+    fn part2(batch: &Self::Input) -> AnyResult<usize> {
+        Ok(count_valid(batch, &Schema::puzzle()))
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result as AnyResult};
+
+use crate::{parse_lines_with, Grid, Solution};
+
+/// Shape of the seats of a plane, rows and columns must be powers of two so
+/// every seat can be found halving them on each character of a pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cabin {
+    rows: usize,
+    columns: usize,
+}
+
+impl Cabin {
+    /// Cabin of the puzzle, 128 rows of 8 seats.
+    pub const PUZZLE: Cabin = Cabin {
+        rows: 128,
+        columns: 8,
+    };
+
+    /// Most seats a cabin can have, so a `SeatMap` of any cabin fits in
+    /// memory.
+    pub const MAX_SEATS: usize = 1 << 24;
+
+    pub fn new(rows: usize, columns: usize) -> AnyResult<Self> {
+        if !rows.is_power_of_two() || !columns.is_power_of_two() {
+            bail!(
+                "Rows and columns must be powers of two, got {}x{}",
+                rows,
+                columns
+            );
+        }
+
+        match rows.checked_mul(columns) {
+            Some(seats) if seats <= Self::MAX_SEATS => Ok(Self { rows, columns }),
+            _ => bail!(
+                "Cabin too big, {}x{} is over {} seats",
+                rows,
+                columns,
+                Self::MAX_SEATS
+            ),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Number of seats, ids go from 0 up to this number.
+    pub fn seats(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// Characters used to pick the row, and then the column.
+    fn row_chars(&self) -> usize {
+        self.rows.trailing_zeros() as usize
+    }
+
+    fn column_chars(&self) -> usize {
+        self.columns.trailing_zeros() as usize
+    }
+}
+
+/// Seat given by a boarding pass, written as `F` (front) or `B` (back) for
+/// each half of the rows picked, and `L` (left) or `R` (right) for each half
+/// of the columns, e.g. `FBFBBFFRLR` is row 44 and column 5 of the puzzle's
+/// cabin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardingPass {
+    pub row: usize,
+    pub column: usize,
+    cabin: Cabin,
+}
+
+impl BoardingPass {
+    pub fn new(cabin: Cabin, row: usize, column: usize) -> AnyResult<Self> {
+        if row >= cabin.rows || column >= cabin.columns {
+            bail!(
+                "Seat ({}, {}) is outside of a {}x{} cabin",
+                row,
+                column,
+                cabin.rows,
+                cabin.columns
+            );
+        }
+
+        Ok(Self { row, column, cabin })
+    }
+
+    /// Pass of the seat with `id`, see `BoardingPass::id`.
+    pub fn from_id(cabin: Cabin, id: usize) -> AnyResult<Self> {
+        if id >= cabin.seats() {
+            bail!(
+                "Seat id {} is outside of a {}-seat cabin",
+                id,
+                cabin.seats()
+            );
+        }
+
+        Self::new(cabin, id / cabin.columns, id % cabin.columns)
+    }
+
+    /// Decode a pass for `cabin`.
+    pub fn parse(cabin: Cabin, pass: &str) -> AnyResult<Self> {
+        let len = cabin.row_chars() + cabin.column_chars();
+        if pass.chars().count() != len {
+            bail!("Expected {} characters, got {}", len, pass.chars().count());
+        }
+
+        let mut row = 0;
+        let mut column = 0;
+        for (idx, chr) in pass.chars().enumerate() {
+            let (half, zero, one) = if idx < cabin.row_chars() {
+                (&mut row, 'F', 'B')
+            } else {
+                (&mut column, 'L', 'R')
+            };
+
+            let bit = match chr {
+                chr if chr == zero => 0,
+                chr if chr == one => 1,
+                chr => bail!(
+                    "Unexpected {:?} at position {}, expected {} or {}",
+                    chr,
+                    idx + 1,
+                    zero,
+                    one
+                ),
+            };
+
+            *half = *half << 1 | bit;
+        }
+
+        Self::new(cabin, row, column)
+    }
+
+    /// Seat id, the row times the number of columns plus the column.
+    pub fn id(&self) -> usize {
+        self.row * self.cabin.columns + self.column
+    }
+
+    pub fn cabin(&self) -> Cabin {
+        self.cabin
+    }
+}
+
+/// Pass for the puzzle's cabin.
+impl FromStr for BoardingPass {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(Cabin::PUZZLE, s)
+    }
+}
+
+/// Encode the pass back to its characters.
+impl fmt::Display for BoardingPass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits = |num: usize, len: usize, zero: char, one: char| {
+            (0..len)
+                .rev()
+                .map(move |bit| if num >> bit & 1 == 1 { one } else { zero })
+        };
+
+        let rows = bits(self.row, self.cabin.row_chars(), 'F', 'B');
+        let columns = bits(self.column, self.cabin.column_chars(), 'L', 'R');
+        rows.chain(columns).try_for_each(|chr| write!(f, "{}", chr))
+    }
+}
+
+/// Occupancy of every seat of a cabin, counting how many passes were issued
+/// for each one.
+#[derive(Debug, Clone)]
+pub struct SeatMap {
+    cabin: Cabin,
+    passes: Grid<usize>,
+}
+
+impl SeatMap {
+    pub fn new(cabin: Cabin) -> Self {
+        let passes = vec![0; cabin.seats()];
+        let passes = Grid::new(cabin.columns, cabin.rows, passes).unwrap();
+        Self { cabin, passes }
+    }
+
+    /// Build the map of `cabin` from seat ids, fails if any of them is outside
+    /// of it.
+    pub fn from_ids(cabin: Cabin, ids: &[usize]) -> AnyResult<Self> {
+        let mut map = Self::new(cabin);
+        for id in ids {
+            map.add(BoardingPass::from_id(cabin, *id)?)?;
+        }
+
+        Ok(map)
+    }
+
+    /// Record that `pass` was issued.
+    pub fn add(&mut self, pass: BoardingPass) -> AnyResult<()> {
+        if pass.cabin != self.cabin {
+            bail!("Pass {} is for another cabin", pass);
+        }
+
+        self.passes[(pass.column, pass.row)] += 1;
+        Ok(())
+    }
+
+    /// Number of passes issued for the seat with `id`, `None` if there's no
+    /// such seat.
+    pub fn passes(&self, id: usize) -> Option<usize> {
+        (id < self.cabin.seats()).then(|| self.count(id))
+    }
+
+    /// Same as `passes` for an `id` known to be in the cabin.
+    fn count(&self, id: usize) -> usize {
+        self.passes[(id % self.cabin.columns, id / self.cabin.columns)]
+    }
+
+    /// Passes of the seats with no passes issued, ordered by id.
+    pub fn empty_seats(&self) -> impl Iterator<Item = BoardingPass> + '_ {
+        (0..self.cabin.seats())
+            .filter(move |id| self.count(*id) == 0)
+            .map(move |id| BoardingPass::from_id(self.cabin, id).unwrap())
+    }
+
+    /// Ranges of consecutive ids of empty seats.
+    pub fn empty_runs(&self) -> Vec<Range<usize>> {
+        let mut runs: Vec<Range<usize>> = Vec::new();
+        for pass in self.empty_seats() {
+            match runs.last_mut() {
+                Some(run) if run.end == pass.id() => run.end += 1,
+                _ => runs.push(pass.id()..pass.id() + 1),
+            }
+        }
+
+        runs
+    }
+
+    /// Seats issued more than once, along with how many passes they have.
+    pub fn duplicates(&self) -> Vec<(BoardingPass, usize)> {
+        (0..self.cabin.seats())
+            .filter(|id| self.count(*id) > 1)
+            .map(|id| {
+                (
+                    BoardingPass::from_id(self.cabin, id).unwrap(),
+                    self.count(id),
+                )
+            })
+            .collect()
+    }
+
+    /// First empty seat whose neighbours by id are both taken.
+    pub fn missing_seat(&self) -> Option<usize> {
+        self.empty_runs().into_iter().find_map(|run| {
+            let alone = run.len() == 1 && run.start > 0 && run.end < self.cabin.seats();
+            alone.then_some(run.start)
+        })
+    }
+}
+
+/// Draw a row per line, after its number, with `.` for empty seats, `#` for
+/// taken ones and `!` for the ones issued more than once.
+impl fmt::Display for SeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = (self.cabin.rows - 1).to_string().len();
+        for (row, seats) in self.passes.rows().enumerate() {
+            let seats: String = seats
+                .iter()
+                .map(|passes| match passes {
+                    0 => '.',
+                    1 => '#',
+                    _ => '!',
+                })
+                .collect();
+
+            writeln!(f, "{:>width$} {}", row, seats, width = width)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Day 5: Binary Boarding.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Map each line to its seat id and sort them.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        let mut seat_ids = parse_lines_with(input, |line| Ok(line.parse::<BoardingPass>()?.id()))?;
+        seat_ids.sort_unstable();
+        Ok(seat_ids)
+    }
+
+    /// Receives a sorted array of seat ids, returns the max seat id, i.e. the last.
+    fn part1(seat_ids: &Self::Input) -> AnyResult<usize> {
+        seat_ids.last().copied().ok_or_else(|| anyhow!("No seats"))
+    }
+
+    /// Find the only free seat whose neighbours by id are taken.
+    fn part2(seat_ids: &Self::Input) -> AnyResult<usize> {
+        SeatMap::from_ids(Cabin::PUZZLE, seat_ids)?
+            .missing_seat()
+            .ok_or_else(|| anyhow!("No free seat found"))
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result as AnyResult};
+
+use crate::{numbered_groups, Solution};
+
+/// Questions of a customs form, one per letter.
+pub const QUESTIONS: usize = 26;
+
+/// Set of questions answered 'yes', bit `n` being the question `a` + `n`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Answers(u32);
+
+impl Answers {
+    pub const NONE: Answers = Answers(0);
+    pub const ALL: Answers = Answers((1 << QUESTIONS) - 1);
+
+    /// Bit of `question`, if it's one of the form.
+    fn bit(question: char) -> Option<u32> {
+        question
+            .is_ascii_lowercase()
+            .then(|| 1 << (question as u32 - 'a' as u32))
+    }
+
+    pub fn contains(&self, question: char) -> bool {
+        Self::bit(question).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn insert(&mut self, question: char) -> AnyResult<()> {
+        match Self::bit(question) {
+            Some(bit) => self.0 |= bit,
+            None => bail!("Unexpected {:?}, questions go from a to z", question),
+        }
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+
+    /// Questions in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        ('a'..='z').filter(move |question| self.contains(*question))
+    }
+}
+
+/// Answers of a person, the letters of the questions answered 'yes'.
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::NONE;
+        for question in s.trim().chars() {
+            answers.insert(question)?;
+        }
+
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|question| write!(f, "{}", question))
+    }
+}
+
+/// Answers of each person in a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub people: Vec<Answers>,
+}
+
+impl Group {
+    /// Questions to which anyone in the group answered 'yes'.
+    pub fn anyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::NONE, |acc, p| acc.union(*p))
+    }
+
+    /// Questions to which everyone in the group answered 'yes', none if the
+    /// group is empty.
+    pub fn everyone(&self) -> Answers {
+        match self.people.first() {
+            Some(first) => self
+                .people
+                .iter()
+                .fold(*first, |acc, p| acc.intersection(*p)),
+            None => Answers::NONE,
+        }
+    }
+
+    /// Number of people who answered 'yes' to each question.
+    pub fn counts(&self) -> [usize; QUESTIONS] {
+        let mut counts = [0; QUESTIONS];
+        for person in &self.people {
+            for (idx, question) in ('a'..='z').enumerate() {
+                counts[idx] += person.contains(question) as usize;
+            }
+        }
+
+        counts
+    }
+
+    /// Questions answered 'yes' by as many people as `keep` accepts.
+    fn filter(&self, keep: impl Fn(usize) -> bool) -> Answers {
+        let mut answers = Answers::NONE;
+        for (count, question) in self.counts().iter().zip('a'..='z') {
+            if *count > 0 && keep(*count) {
+                answers.insert(question).unwrap();
+            }
+        }
+
+        answers
+    }
+
+    /// Questions to which exactly `k` people answered 'yes', with `k` 0 it's
+    /// always empty as only questions answered by someone are considered.
+    pub fn exactly(&self, k: usize) -> Answers {
+        self.filter(|count| count == k)
+    }
+
+    /// Questions to which more than half of the group answered 'yes'.
+    pub fn majority(&self) -> Answers {
+        self.filter(|count| count > self.people.len() / 2)
+    }
+}
+
+/// How a question was answered across every group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuestionStats {
+    pub question: char,
+    /// People who answered 'yes'.
+    pub people: usize,
+    /// Groups in which anyone answered 'yes'.
+    pub anyone: usize,
+    /// Groups in which everyone answered 'yes'.
+    pub everyone: usize,
+}
+
+/// Statistics of each question, from `a` to `z`.
+pub fn question_stats(groups: &[Group]) -> Vec<QuestionStats> {
+    let mut stats: Vec<_> = ('a'..='z')
+        .map(|question| QuestionStats {
+            question,
+            people: 0,
+            anyone: 0,
+            everyone: 0,
+        })
+        .collect();
+
+    for group in groups {
+        let (anyone, everyone) = (group.anyone(), group.everyone());
+        for (stats, count) in stats.iter_mut().zip(&group.counts()) {
+            stats.people += count;
+            stats.anyone += anyone.contains(stats.question) as usize;
+            stats.everyone += everyone.contains(stats.question) as usize;
+        }
+    }
+
+    stats
+}
+
+/// Sum the size of the answers picked from each group.
+pub fn count_answers(groups: &[Group], pick: impl Fn(&Group) -> Answers) -> usize {
+    groups.iter().map(|group| pick(group).len()).sum()
+}
+
+/// Day 6: Custom Customs.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Group>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Split the answers file in groups, one line per person.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        numbered_groups(input)
+            .map(|lines| {
+                let people = lines
+                    .into_iter()
+                    .map(|(num, line)| {
+                        line.parse()
+                            .with_context(|| format!("Line {}: {:?}", num, line))
+                    })
+                    .collect::<AnyResult<_>>()?;
+
+                Ok(Group { people })
+            })
+            .collect()
+    }
+
+    /// Count the questions to which anyone in a group answered 'yes'.
+    fn part1(groups: &Self::Input) -> AnyResult<usize> {
+        Ok(count_answers(groups, Group::anyone))
+    }
+
+    /// Count the questions to which everyone in a group answered 'yes'.
+    fn part2(groups: &Self::Input) -> AnyResult<usize> {
+        Ok(count_answers(groups, Group::everyone))
+    }
+}
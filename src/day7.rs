@@ -0,0 +1,309 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::{fmt, iter};
+
+use anyhow::{anyhow, bail, Result as AnyResult};
+use itertools::Itertools;
+
+use crate::Solution;
+
+/// Single bag, holds a list of bags ids and the required number of them.
+#[derive(Debug, Clone)]
+pub struct Bag {
+    contains: Vec<(String, usize)>,
+}
+
+/// Bags graph represented as an adjacency list.
+#[derive(Debug, Clone)]
+pub struct BagsGraph {
+    list: HashMap<String, Bag>,
+    /// Bags with more than one rule and the line of each extra rule, only
+    /// the last rule of a bag is kept.
+    duplicates: Vec<(String, usize)>,
+    /// Lines that aren't rules, along with their number.
+    malformed: Vec<(usize, String)>,
+}
+
+/// Problem found validating the rules of a `BagsGraph`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    /// Line that isn't a rule.
+    Malformed { line: usize, text: String },
+    /// Bag with a rule already given before `line`.
+    Duplicate { bag: String, line: usize },
+    /// Bag that must be inside `outer` but has no rule.
+    Undefined { bag: String, outer: String },
+    /// Bags that end up containing themselves, each one contains the next
+    /// and the last one is the first again.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::Malformed { line, text } => {
+                write!(f, "Line {}: not a rule: {:?}", line, text)
+            }
+            RuleError::Duplicate { bag, line } => {
+                write!(f, "Line {}: {} bags already have a rule", line, bag)
+            }
+            RuleError::Undefined { bag, outer } => {
+                write!(f, "No rule for {} bags, inside {} bags", bag, outer)
+            }
+            RuleError::Cycle(path) => write!(f, "Bags contain themselves: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl BagsGraph {
+    /// Parse the rules to build the adjacency list.
+    pub fn parse(input: &str) -> Self {
+        fn parse_line(line: &str) -> Option<(String, Bag)> {
+            let mut splitted = line.split("contain");
+
+            let this_bag = splitted.next()?;
+            let this_bag = this_bag.trim().split(' ').take(2).collect::<String>();
+
+            let contains = splitted.next()?;
+            let contains = contains.trim().split(',');
+
+            let contains = contains
+                .filter_map(|item| -> Option<(String, usize)> {
+                    let mut splitted = item.trim().split(' ');
+
+                    let qty = match splitted.next()?.parse::<usize>() {
+                        Ok(num) => num,
+                        Err(_) => return None,
+                    };
+
+                    let color = splitted.take(2).collect::<String>();
+
+                    Some((color, qty))
+                })
+                .collect();
+
+            Some((this_bag, Bag { contains }))
+        }
+
+        let mut list = HashMap::new();
+        let mut duplicates = Vec::new();
+        let mut malformed = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match parse_line(line) {
+                Some((color, bag)) => {
+                    if list.insert(color.clone(), bag).is_some() {
+                        duplicates.push((color, idx + 1));
+                    }
+                }
+
+                None => malformed.push((idx + 1, line.to_owned())),
+            }
+        }
+
+        Self {
+            list,
+            duplicates,
+            malformed,
+        }
+    }
+
+    /// Check that every line is a rule, that every bag has a single rule,
+    /// that the bags inside of them have rules too, and that no bag ends up
+    /// containing itself.
+    pub fn validate(&self) -> Result<(), Vec<RuleError>> {
+        let malformed = self
+            .malformed
+            .iter()
+            .map(|(line, text)| RuleError::Malformed {
+                line: *line,
+                text: text.clone(),
+            });
+
+        let duplicates = self
+            .duplicates
+            .iter()
+            .map(|(bag, line)| RuleError::Duplicate {
+                bag: bag.clone(),
+                line: *line,
+            });
+
+        let mut errors: Vec<_> = malformed.chain(duplicates).collect();
+
+        let mut bags: Vec<_> = self.list.keys().map(String::as_str).collect();
+        bags.sort_unstable();
+        for outer in &bags {
+            for (bag, _) in &self.list[*outer].contains {
+                if !self.list.contains_key(bag) {
+                    errors.push(RuleError::Undefined {
+                        bag: bag.clone(),
+                        outer: outer.to_string(),
+                    });
+                }
+            }
+        }
+
+        errors.extend(self.cycles(&bags).into_iter().map(RuleError::Cycle));
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Find cycles walking the graph in depth from each of `bags`, a cycle is
+    /// reported once for each rule that closes it.
+    fn cycles<'a>(&'a self, bags: &[&'a str]) -> Vec<Vec<String>> {
+        let inside = |bag: &str| self.list.get(bag).map_or(&[][..], |bag| &bag.contains[..]);
+
+        let mut cycles = Vec::new();
+        let mut done = HashSet::new();
+        for start in bags {
+            if done.contains(start) {
+                continue;
+            }
+
+            // Current path, along with the next bag to visit inside each one.
+            let mut path: Vec<(&str, usize)> = vec![(start, 0)];
+            let mut on_path: HashSet<&str> = iter::once(*start).collect();
+            while let Some((bag, next)) = path.last_mut() {
+                let inner = match inside(bag).get(*next) {
+                    Some((inner, _)) => inner.as_str(),
+                    None => {
+                        done.insert(*bag);
+                        on_path.remove(*bag);
+                        path.pop();
+                        continue;
+                    }
+                };
+
+                *next += 1;
+                if on_path.contains(inner) {
+                    let pos = path.iter().position(|(bag, _)| *bag == inner).unwrap();
+                    let mut cycle: Vec<_> =
+                        path[pos..].iter().map(|(bag, _)| bag.to_string()).collect();
+                    cycle.push(inner.to_owned());
+                    cycles.push(cycle);
+                } else if !done.contains(inner) {
+                    on_path.insert(inner);
+                    path.push((inner, 0));
+                }
+            }
+        }
+
+        cycles
+    }
+
+    /// Find every bag that directly or indirectly can hold `target`, walking
+    /// the rules backwards from it.
+    pub fn holds<'a>(&'a self, target: &str) -> impl Iterator<Item = &'a str> {
+        let mut outer: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (color, bag) in &self.list {
+            for (inner, _) in &bag.contains {
+                outer.entry(inner).or_default().push(color);
+            }
+        }
+
+        let mut found = HashSet::new();
+        let mut queue: VecDeque<_> = outer.get(target).into_iter().flatten().copied().collect();
+        while let Some(color) = queue.pop_front() {
+            if found.insert(color) {
+                queue.extend(outer.get(color).into_iter().flatten().copied());
+            }
+        }
+
+        found.into_iter()
+    }
+
+    /// Count how many individual bags are required inside `target`, fails if
+    /// the count doesn't fit in a `usize`.
+    pub fn inside_count(&self, target: &str) -> AnyResult<usize> {
+        let mut counts = HashMap::new();
+        self.count_into(target, &mut counts)?;
+        Ok(counts[target])
+    }
+
+    /// Count the bags inside `target` and every bag it (indirectly) contains,
+    /// storing them in `counts`. Each bag is visited after the bags inside of
+    /// it, so each count is computed once no matter how many bags hold it.
+    fn count_into<'a>(
+        &'a self,
+        target: &'a str,
+        counts: &mut HashMap<&'a str, usize>,
+    ) -> AnyResult<()> {
+        let bag = |color: &str| {
+            self.list
+                .get(color)
+                .ok_or_else(|| anyhow!("No rule for {} bags", color))
+        };
+
+        // Bags whose contents are being counted, i.e. the current path.
+        let mut pending = HashSet::new();
+        let mut stack = vec![(target, false)];
+        while let Some((color, ready)) = stack.pop() {
+            if ready {
+                let mut total = 0usize;
+                for (inner, qty) in &bag(color)?.contains {
+                    total = counts[inner.as_str()]
+                        .checked_add(1)
+                        .and_then(|bags| bags.checked_mul(*qty))
+                        .and_then(|bags| bags.checked_add(total))
+                        .ok_or_else(|| anyhow!("Too many bags inside {} bags", color))?;
+                }
+
+                pending.remove(color);
+                counts.insert(color, total);
+                continue;
+            }
+
+            if counts.contains_key(color) {
+                continue;
+            }
+
+            pending.insert(color);
+            stack.push((color, true));
+            for (inner, _) in &bag(color)?.contains {
+                if pending.contains(inner.as_str()) {
+                    bail!("{} bags end up containing themselves", inner);
+                }
+
+                if !counts.contains_key(inner.as_str()) {
+                    stack.push((inner, false));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Day 7: Handy Haversacks.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = BagsGraph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Parse the rules and check they make sense.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        let bags = BagsGraph::parse(input);
+        if let Err(errors) = bags.validate() {
+            bail!("{}", errors.iter().format("; "));
+        }
+
+        Ok(bags)
+    }
+
+    /// Count how many bags can eventually hold a shiny gold bag.
+    fn part1(bags: &Self::Input) -> AnyResult<usize> {
+        Ok(bags.holds("shinygold").count())
+    }
+
+    /// Count how many bags are required inside a shiny gold bag.
+    fn part2(bags: &Self::Input) -> AnyResult<usize> {
+        bags.inside_count("shinygold")
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail, Context, Result as AnyResult};
+use boolinator::Boolinator;
+
+use crate::{parse_lines_with, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Index(i32);
+
+impl Index {
+    /// Moves an index according to the instruction received.
+    pub fn step(&mut self, inst: &Instruction) {
+        match inst {
+            Instruction::Jmp(offset) => self.0 += offset,
+            _ => self.0 += 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn parse(input: &str) -> AnyResult<Self> {
+        fn parse_line(line: &str) -> AnyResult<Instruction> {
+            let (key, val) = line
+                .split_once(' ')
+                .ok_or_else(|| anyhow!("Expected an instruction and a value"))?;
+            let val = val
+                .parse::<i32>()
+                .with_context(|| format!("Invalid value: {:?}", val))?;
+
+            let inst = match key {
+                "acc" => Instruction::Acc(val),
+                "jmp" => Instruction::Jmp(val),
+                "nop" => Instruction::Nop(val),
+                _ => bail!("Unknown instruction: {:?}", key),
+            };
+
+            Ok(inst)
+        }
+
+        let instructions = parse_lines_with(input, parse_line)?;
+
+        Ok(Self { instructions })
+    }
+
+    /// Start at the first instruction (0), if it's `acc` increase the value of
+    /// the accumulator. Each processed instruction index is stored in a set,
+    /// whenever we find reach an already executed instruction and if `avoid_loop`
+    /// is true we end the program and return the value of the accumulator.
+    pub fn run(&mut self, avoid_loop: bool) -> i32 {
+        let mut counter = Index(0);
+        let mut accumulator = 0i32;
+        let mut executed = avoid_loop.as_some_from(HashSet::new);
+
+        while let Some(instruction) = self.instructions.get(counter.0 as usize) {
+            if let Some(executed) = executed.as_mut() {
+                if executed.contains(&counter) {
+                    return accumulator;
+                }
+
+                executed.insert(counter);
+            }
+
+            if let Instruction::Acc(val) = instruction {
+                accumulator += val;
+            }
+
+            counter.step(instruction);
+        }
+
+        accumulator
+    }
+
+    /// Change one `nop` to `jmp`, or one `jmp` to `nop` in order to fix an
+    /// infinite loop.
+    pub fn fix(&mut self) -> &mut Self {
+        let fix_idx = FixLoop::new(self.clone()).get_fix_idx();
+
+        if let Some(fix_idx) = fix_idx {
+            if let Some(instruction) = self.instructions.get_mut(fix_idx) {
+                match instruction {
+                    Instruction::Nop(val) => *instruction = Instruction::Jmp(*val),
+                    Instruction::Jmp(val) => *instruction = Instruction::Nop(*val),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        self
+    }
+}
+
+/// Used to fix the program infinite loop.
+#[derive(Debug, Clone)]
+struct FixLoop {
+    program: Program,
+}
+
+impl FixLoop {
+    fn new(program: Program) -> Self {
+        Self { program }
+    }
+
+    /// Runs the program starting from one specific point and checks whether
+    /// we find a loop.
+    fn still_loop(&self, mut counter: Index, mut executed: HashSet<Index>) -> bool {
+        while let Some(instruction) = self.program.instructions.get(counter.0 as usize) {
+            if executed.contains(&counter) {
+                return false;
+            }
+
+            executed.insert(counter);
+            counter.step(instruction);
+        }
+
+        true
+    }
+
+    /// Returns the index, if there's one, of the instruction to change in order to fix the
+    /// infinite loop.
+    fn get_fix_idx(&mut self) -> Option<usize> {
+        let mut counter = Index(0);
+        let mut executed = HashSet::new();
+
+        // Change in-place one instruction an simulate with `still_loop`, if it
+        // doesn't loop anymore we found the index we need to change.
+        while let Some(instruction) = self.program.instructions.get(counter.0 as usize).cloned() {
+            match instruction {
+                Instruction::Nop(val) => {
+                    self.program.instructions[counter.0 as usize] = Instruction::Jmp(val);
+                    if !self.still_loop(counter, executed.clone()) {
+                        self.program.instructions[counter.0 as usize] = Instruction::Nop(val);
+                    } else {
+                        return Some(counter.0 as usize);
+                    }
+                }
+
+                Instruction::Jmp(val) => {
+                    self.program.instructions[counter.0 as usize] = Instruction::Nop(val);
+                    if !self.still_loop(counter, executed.clone()) {
+                        self.program.instructions[counter.0 as usize] = Instruction::Jmp(val);
+                    } else {
+                        return Some(counter.0 as usize);
+                    }
+                }
+
+                _ => {}
+            }
+
+            executed.insert(counter);
+            counter.step(&instruction);
+        }
+
+        None
+    }
+}
+
+/// Day 8: Handheld Halting.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Program;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        Program::parse(input)
+    }
+
+    /// Value of the accumulator right before the program loops.
+    fn part1(program: &Self::Input) -> AnyResult<i32> {
+        Ok(program.clone().run(true))
+    }
+
+    /// Value of the accumulator after fixing the infinite loop.
+    fn part2(program: &Self::Input) -> AnyResult<i32> {
+        Ok(program.clone().fix().run(false))
+    }
+}
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result as AnyResult};
+use itertools::Itertools;
+use itertools::MinMaxResult;
+
+use crate::{parse_lines, Solution};
+
+/// Check wheter a number is valid given the preamble.
+fn is_valid(preamble: &[usize], number: usize) -> bool {
+    let preamble: HashSet<_> = preamble.iter().collect();
+
+    for a in &preamble {
+        if **a > number {
+            continue;
+        }
+
+        let c = number - *a;
+        if preamble.contains(&c) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Find the invalid number in the data, if there is one.
+pub fn find_invalid(data: &[usize], preamble_size: usize) -> Option<usize> {
+    for window in data.windows(preamble_size + 1) {
+        let preamble = &window[..preamble_size];
+        let num_to_test = window[preamble_size];
+        if !is_valid(preamble, num_to_test) {
+            return Some(num_to_test);
+        }
+    }
+
+    None
+}
+
+/// Find the weakness in the data given the invalid number, if there is one.
+pub fn find_weakness(data: &[usize], invalid_number: usize) -> Option<usize> {
+    if data.len() < 2 {
+        return None;
+    }
+
+    let mut lo = 0;
+    let mut hi = 0;
+    let mut sum = data[lo];
+
+    loop {
+        match sum.cmp(&invalid_number) {
+            Ordering::Equal => {
+                let res = data[lo..hi + 1].iter().minmax();
+                match res {
+                    // At least two elements in between lo..hi + 1.
+                    MinMaxResult::MinMax(min, max) => {
+                        return Some(min + max);
+                    }
+
+                    // There's only one or zero element(s) in
+                    // between lo..hi + 1.
+                    _ => return None,
+                }
+            }
+
+            Ordering::Greater => {
+                sum -= data[lo];
+                lo += 1;
+            }
+
+            Ordering::Less => {
+                hi += 1;
+                sum += data[hi];
+            }
+        }
+    }
+}
+
+/// Day 9: Encoding Error.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Parse the numbers of every line.
+    fn parse(input: &str) -> AnyResult<Self::Input> {
+        parse_lines(input)
+    }
+
+    /// Find the first number which isn't the sum of two of the 25 numbers before it.
+    fn part1(data: &Self::Input) -> AnyResult<usize> {
+        find_invalid(data, 25).ok_or_else(|| anyhow!("No invalid number"))
+    }
+
+    /// Find the encryption weakness for the invalid number.
+    fn part2(data: &Self::Input) -> AnyResult<usize> {
+        find_weakness(data, Self::part1(data)?).ok_or_else(|| anyhow!("No weakness found"))
+    }
+}
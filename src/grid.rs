@@ -0,0 +1,232 @@
+use std::ops::{Index, IndexMut};
+
+use anyhow::{bail, Context, Result as AnyResult};
+
+/// Offsets to the 4 orthogonal neighbours of a cell, as `(x, y)`.
+pub const NEIGHBOURS_4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the 8 neighbours of a cell, diagonals included, as `(x, y)`.
+pub const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Two dimensional grid stored row by row in a single buffer. Positions are
+/// given as `(x, y)`, i.e. column and row, where `(0, 0)` is the top left
+/// cell. Lookups take signed coordinates so callers can step outside of the
+/// grid freely, if horizontal wrap is enabled the grid repeats itself to the
+/// left and to the right.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrap_x: bool,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its cells given row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> AnyResult<Self> {
+        if width * height != cells.len() {
+            bail!(
+                "Expected {} cells for a {}x{} grid, got {}",
+                width * height,
+                width,
+                height,
+                cells.len()
+            );
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+            wrap_x: false,
+        })
+    }
+
+    /// Build a grid from a character map, one row per line, mapping every
+    /// character with `cell`. All rows must have the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> AnyResult<T>) -> AnyResult<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (col, chr) in line.chars().enumerate() {
+                let parsed = cell(chr)
+                    .with_context(|| format!("Line {}, column {}: {:?}", row + 1, col + 1, chr))?;
+                cells.push(parsed);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => bail!(
+                    "Line {}: expected {} cells, got {}",
+                    row + 1,
+                    width,
+                    row_width
+                ),
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    /// Enable or disable horizontal wrap.
+    pub fn with_wrap_x(mut self, wrap_x: bool) -> Self {
+        self.wrap_x = wrap_x;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn wraps_x(&self) -> bool {
+        self.wrap_x
+    }
+
+    /// Index in the buffer of the cell at `(x, y)`, if it's inside the grid
+    /// (or wraps back into it).
+    fn offset(&self, x: i64, y: i64) -> Option<usize> {
+        if y < 0 || y >= self.height as i64 || self.width == 0 {
+            return None;
+        }
+
+        let x = if self.wrap_x {
+            x.rem_euclid(self.width as i64)
+        } else if x < 0 || x >= self.width as i64 {
+            return None;
+        } else {
+            x
+        };
+
+        Some(y as usize * self.width + x as usize)
+    }
+
+    /// Return the cell at `(x, y)` where both can be negative.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.offset(x, y).map(|offset| &self.cells[offset])
+    }
+
+    /// Mutable version of `get`.
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.offset(x, y).map(move |offset| &mut self.cells[offset])
+    }
+
+    /// Iterate over the cells row by row along with their position.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset % width, offset / width), cell))
+    }
+
+    /// Iterate over the rows of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics with a zero size.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterate over the cells at `offsets` from `(x, y)`, skipping the ones
+    /// that fall outside of the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        x: i64,
+        y: i64,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| self.get(x + dx, y + dy))
+    }
+
+    /// Iterate over the 4 orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: i64, y: i64) -> impl Iterator<Item = &T> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Iterate over the 8 neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: i64, y: i64) -> impl Iterator<Item = &T> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    /// Cast a ray from `(x, y)` moving `(dx, dy)` at each step, see `Ray`.
+    pub fn ray(&self, x: i64, y: i64, (dx, dy): (i64, i64)) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            pos: (x, y),
+            step: (dx, dy),
+        }
+    }
+
+    /// Build a new grid with the same shape mapping every cell with `f`,
+    /// which also receives the position of the cell.
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+            width: self.width,
+            height: self.height,
+            wrap_x: self.wrap_x,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x as i64, y as i64)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x as i64, y as i64)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", x, y))
+    }
+}
+
+/// Iterator over the cells seen from a starting position moving in a fixed
+/// direction, the starting cell excluded. It yields the position, without
+/// wrapping, and the cell, and stops once it leaves the grid. A ray moving
+/// only horizontally over a grid that wraps never ends, and a ray that
+/// doesn't move yields nothing.
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: (i64, i64),
+    step: (i64, i64),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((i64, i64), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == (0, 0) {
+            return None;
+        }
+
+        self.pos = (self.pos.0 + self.step.0, self.pos.1 + self.step.1);
+        let cell = self.grid.get(self.pos.0, self.pos.1)?;
+        Some((self.pos, cell))
+    }
+}
@@ -1,12 +1,161 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
 use std::path::Path;
+use std::str::FromStr;
 
-use anyhow::Result as AnyResult;
+use anyhow::{anyhow, bail, Context, Error, Result as AnyResult};
 
-/// Open a file and return an iterator that yields lines.
-pub fn lines(path: impl AsRef<Path>) -> AnyResult<impl Iterator<Item = String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    Ok(reader.lines().filter_map(|line| line.ok()))
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod grid;
+pub mod records;
+
+pub use grid::Grid;
+pub use records::{groups, numbered_groups, paragraphs};
+
+/// Solver for one day's puzzle, split in a parsing stage and one stage for
+/// each part of the puzzle.
+pub trait Solution {
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+    /// Answer of the first part.
+    type Part1: Display;
+    /// Answer of the second part.
+    type Part2: Display;
+
+    /// Parse the whole puzzle input.
+    fn parse(input: &str) -> AnyResult<Self::Input>;
+
+    /// Solve the first part of the puzzle.
+    fn part1(input: &Self::Input) -> AnyResult<Self::Part1>;
+
+    /// Solve the second part of the puzzle.
+    fn part2(input: &Self::Input) -> AnyResult<Self::Part2>;
+}
+
+/// Parse `input` and print the answers of `S` for day `day`, if `part` is
+/// `None` both parts are solved.
+pub fn solve<S: Solution>(day: u32, part: Option<u32>, input: &str) -> AnyResult<()> {
+    let input = S::parse(input)?;
+
+    match part {
+        None => {
+            println!("Day {}, Part 1: {}", day, S::part1(&input)?);
+            println!("Day {}, Part 2: {}", day, S::part2(&input)?);
+        }
+
+        Some(1) => println!("Day {}, Part 1: {}", day, S::part1(&input)?),
+        Some(2) => println!("Day {}, Part 2: {}", day, S::part2(&input)?),
+        Some(part) => bail!("Invalid part: {}, expected 1 or 2", part),
+    }
+
+    Ok(())
+}
+
+/// Same as `solve` but reading the input from `path`, see `read_input`.
+pub fn run<S: Solution>(day: u32, part: Option<u32>, path: impl AsRef<Path>) -> AnyResult<()> {
+    let input = read_input(path)?;
+    solve::<S>(day, part, &input)
+}
+
+/// Entry point of the `dayN` binaries, solve both parts reading the input
+/// from the path chosen by `input_path`.
+pub fn run_default<S: Solution>(day: u32) -> AnyResult<()> {
+    run::<S>(day, None, input_path(day, env::args().nth(1)))
+}
+
+/// Environment variable that overrides the default input path.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Path that marks the input should be read from stdin.
+pub const STDIN_PATH: &str = "-";
+
+/// Choose where to read the input of `day` from, in order of preference:
+/// - `arg`, usually given in the command line.
+/// - The `AOC_INPUT` environment variable.
+/// - The default `files/dayN/input.txt`.
+pub fn input_path(day: u32, arg: Option<String>) -> String {
+    arg.or_else(|| env::var(INPUT_ENV).ok())
+        .unwrap_or_else(|| format!("files/day{}/input.txt", day))
+}
+
+/// Take the input path out of the command line arguments of a binary. Only
+/// the first argument can be the input, so it can be left out before other
+/// arguments: it is if it's `-` or an existing path, or if `is_arg` doesn't
+/// take it for another argument, so a misspelt path is reported as missing.
+pub fn take_input_arg(args: &mut Vec<String>, is_arg: impl Fn(&str) -> bool) -> Option<String> {
+    match args.first() {
+        Some(arg) if arg == STDIN_PATH || Path::new(arg).exists() || !is_arg(arg) => {
+            Some(args.remove(0))
+        }
+        _ => None,
+    }
+}
+
+/// Read the whole input from the file at `path`, or from stdin if `path`
+/// is `-`. Invalid UTF-8 is reported with the number of the line holding it.
+pub fn read_input(path: impl AsRef<Path>) -> AnyResult<String> {
+    let path = path.as_ref();
+    let stdin = path == Path::new(STDIN_PATH);
+    let bytes = if stdin {
+        let mut bytes = Vec::new();
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .context("Failed to read stdin")?;
+
+        bytes
+    } else {
+        fs::read(path).with_context(|| format!("Failed to load {}", path.display()))?
+    };
+
+    String::from_utf8(bytes).map_err(|err| {
+        let valid = &err.as_bytes()[..err.utf8_error().valid_up_to()];
+        let line = valid.iter().filter(|byte| **byte == b'\n').count() + 1;
+        let name = if stdin {
+            "stdin".into()
+        } else {
+            path.display().to_string()
+        };
+        anyhow!("Failed to read {}:{}: invalid UTF-8", name, line)
+    })
+}
+
+/// Parse every line of `input` with `parse`, failing on the first line that
+/// can't be parsed and reporting its number and contents. Blank lines are
+/// skipped.
+pub fn parse_lines_with<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> AnyResult<T>,
+) -> AnyResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| parse(line).with_context(|| format!("Line {}: {:?}", idx + 1, line)))
+        .collect()
+}
+
+/// Parse every line of `input` as a `T`, see `parse_lines_with`.
+pub fn parse_lines<T>(input: &str) -> AnyResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    parse_lines_with(input, |line| line.parse().map_err(Into::into))
 }
@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+/// Iterator over the byte ranges of the records of a text, a record is a
+/// group of consecutive non-blank lines. Several blank lines in a row
+/// separate records as a single one would, and the last record doesn't need
+/// a trailing blank line.
+#[derive(Debug, Clone)]
+struct Records<'a> {
+    input: &'a str,
+    pos: usize,
+    /// Number of lines read so far.
+    line: usize,
+}
+
+impl<'a> Records<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            line: 0,
+        }
+    }
+
+    /// Return the range of the next line, without its line terminator, and
+    /// move past it.
+    fn next_line(&mut self) -> Option<Range<usize>> {
+        if self.pos >= self.input.len() {
+            return None;
+        }
+
+        let start = self.pos;
+        let end = self.input[start..]
+            .find('\n')
+            .map_or(self.input.len(), |idx| start + idx);
+
+        self.pos = end + 1;
+        self.line += 1;
+        let end = if self.input[start..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        };
+
+        Some(start..end)
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    /// Line number (starting at 1) and range of each line of the record.
+    type Item = Vec<(usize, Range<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = Vec::new();
+        while let Some(line) = self.next_line() {
+            if self.input[line.clone()].trim().is_empty() {
+                if record.is_empty() {
+                    // Skip blank lines before the record starts.
+                    continue;
+                } else {
+                    break;
+                }
+            }
+
+            record.push((self.line, line));
+        }
+
+        if record.is_empty() {
+            None
+        } else {
+            Some(record)
+        }
+    }
+}
+
+/// Iterator over groups of lines separated by blank lines.
+#[derive(Debug, Clone)]
+pub struct Groups<'a> {
+    records: Records<'a>,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.records.input;
+        let record = self.records.next()?;
+        Some(record.into_iter().map(|(_, line)| &input[line]).collect())
+    }
+}
+
+/// Iterator over groups of lines separated by blank lines, along with the
+/// number of each line (starting at 1).
+#[derive(Debug, Clone)]
+pub struct NumberedGroups<'a> {
+    records: Records<'a>,
+}
+
+impl<'a> Iterator for NumberedGroups<'a> {
+    type Item = Vec<(usize, &'a str)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.records.input;
+        let record = self.records.next()?;
+        Some(
+            record
+                .into_iter()
+                .map(|(num, line)| (num, &input[line]))
+                .collect(),
+        )
+    }
+}
+
+/// Iterator over paragraphs, i.e. the text spanning each group of lines
+/// separated by blank lines, inner line terminators included.
+#[derive(Debug, Clone)]
+pub struct Paragraphs<'a> {
+    records: Records<'a>,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.records.input;
+        let record = self.records.next()?;
+        let start = record.first()?.1.start;
+        let end = record.last()?.1.end;
+        Some(&input[start..end])
+    }
+}
+
+/// Split `input` in groups of lines separated by blank lines.
+pub fn groups(input: &str) -> Groups<'_> {
+    Groups {
+        records: Records::new(input),
+    }
+}
+
+/// Split `input` in groups of lines separated by blank lines, numbering
+/// each line.
+pub fn numbered_groups(input: &str) -> NumberedGroups<'_> {
+    NumberedGroups {
+        records: Records::new(input),
+    }
+}
+
+/// Split `input` in paragraphs separated by blank lines.
+pub fn paragraphs(input: &str) -> Paragraphs<'_> {
+    Paragraphs {
+        records: Records::new(input),
+    }
+}
@@ -0,0 +1,227 @@
+use aoc_2020::day1::Day1;
+use aoc_2020::day10::Day10;
+use aoc_2020::day11::Day11;
+use aoc_2020::day12::Day12;
+use aoc_2020::day13::Day13;
+use aoc_2020::day14::Day14;
+use aoc_2020::day15::{self, Day15};
+use aoc_2020::day16::{Day16, Notes};
+use aoc_2020::day2::Day2;
+use aoc_2020::day3::Day3;
+use aoc_2020::day4::Day4;
+use aoc_2020::day5::Day5;
+use aoc_2020::day6::Day6;
+use aoc_2020::day7::Day7;
+use aoc_2020::day8::Day8;
+use aoc_2020::day9::{self, Day9};
+use aoc_2020::{read_input, Solution};
+
+/// Parse the input at `path` with `S`.
+fn parse<S: Solution>(path: &str) -> S::Input {
+    S::parse(&read_input(path).unwrap()).unwrap()
+}
+
+/// Answer of the first part of `S` for the input at `path`.
+fn part1<S: Solution>(path: &str) -> String {
+    S::part1(&parse::<S>(path)).unwrap().to_string()
+}
+
+/// Answer of the second part of `S` for the input at `path`.
+fn part2<S: Solution>(path: &str) -> String {
+    S::part2(&parse::<S>(path)).unwrap().to_string()
+}
+
+/// Answers of both parts of `S` for the input at `path`.
+fn answers<S: Solution>(path: &str) -> (String, String) {
+    let input = parse::<S>(path);
+    let part1 = S::part1(&input).unwrap().to_string();
+    let part2 = S::part2(&input).unwrap().to_string();
+    (part1, part2)
+}
+
+fn pair(part1: &str, part2: &str) -> (String, String) {
+    (part1.to_owned(), part2.to_owned())
+}
+
+#[test]
+fn day1_input() {
+    let answers = answers::<Day1>("files/day1/input.txt");
+    assert_eq!(answers, pair("445536", "138688160"));
+}
+
+#[test]
+fn day2_input() {
+    let answers = answers::<Day2>("files/day2/input.txt");
+    assert_eq!(answers, pair("643", "388"));
+}
+
+#[test]
+fn day3_fixtures() {
+    assert_eq!(answers::<Day3>("files/day3/test.txt"), pair("7", "336"));
+}
+
+#[test]
+fn day3_input() {
+    let answers = answers::<Day3>("files/day3/input.txt");
+    assert_eq!(answers, pair("191", "1478615040"));
+}
+
+#[test]
+fn day4_fixtures() {
+    // The last passport is valid and isn't followed by a blank line.
+    assert_eq!(part1::<Day4>("files/day4/test1.txt"), "2");
+    assert_eq!(part2::<Day4>("files/day4/test2.txt"), "0");
+    assert_eq!(part2::<Day4>("files/day4/test3.txt"), "4");
+}
+
+#[test]
+fn day4_input() {
+    let answers = answers::<Day4>("files/day4/input.txt");
+    assert_eq!(answers, pair("256", "198"));
+}
+
+#[test]
+fn day5_input() {
+    let answers = answers::<Day5>("files/day5/input.txt");
+    assert_eq!(answers, pair("908", "619"));
+}
+
+#[test]
+fn day6_fixtures() {
+    assert_eq!(answers::<Day6>("files/day6/test.txt"), pair("11", "6"));
+}
+
+#[test]
+fn day6_input() {
+    let answers = answers::<Day6>("files/day6/input.txt");
+    assert_eq!(answers, pair("6170", "2947"));
+}
+
+#[test]
+fn day7_fixtures() {
+    assert_eq!(answers::<Day7>("files/day7/test1.txt"), pair("4", "32"));
+    assert_eq!(part2::<Day7>("files/day7/test2.txt"), "126");
+}
+
+#[test]
+fn day7_input() {
+    let answers = answers::<Day7>("files/day7/input.txt");
+    assert_eq!(answers, pair("205", "80902"));
+}
+
+#[test]
+fn day8_fixtures() {
+    assert_eq!(answers::<Day8>("files/day8/test.txt"), pair("5", "8"));
+}
+
+#[test]
+fn day8_input() {
+    let answers = answers::<Day8>("files/day8/input.txt");
+    assert_eq!(answers, pair("1317", "1033"));
+}
+
+#[test]
+fn day9_fixtures() {
+    // The example uses a preamble of 5 numbers instead of 25.
+    let data = parse::<Day9>("files/day9/test.txt");
+    let invalid = day9::find_invalid(&data, 5);
+    assert_eq!(invalid, Some(127));
+    assert_eq!(day9::find_weakness(&data, 127), Some(62));
+}
+
+#[test]
+fn day9_input() {
+    let answers = answers::<Day9>("files/day9/input.txt");
+    assert_eq!(answers, pair("88311122", "13549369"));
+}
+
+#[test]
+fn day10_fixtures() {
+    assert_eq!(answers::<Day10>("files/day10/test1.txt"), pair("35", "8"));
+    assert_eq!(
+        answers::<Day10>("files/day10/test2.txt"),
+        pair("220", "19208")
+    );
+}
+
+#[test]
+fn day10_input() {
+    let answers = answers::<Day10>("files/day10/input.txt");
+    assert_eq!(answers, pair("2210", "7086739046912"));
+}
+
+#[test]
+fn day11_fixtures() {
+    assert_eq!(answers::<Day11>("files/day11/test.txt"), pair("37", "26"));
+}
+
+#[test]
+fn day11_input() {
+    let answers = answers::<Day11>("files/day11/input.txt");
+    assert_eq!(answers, pair("2438", "2174"));
+}
+
+#[test]
+fn day12_fixtures() {
+    assert_eq!(answers::<Day12>("files/day12/test.txt"), pair("25", "286"));
+}
+
+#[test]
+fn day12_input() {
+    let answers = answers::<Day12>("files/day12/input.txt");
+    assert_eq!(answers, pair("2458", "145117"));
+}
+
+#[test]
+fn day13_input() {
+    let answers = answers::<Day13>("files/day13/input.txt");
+    assert_eq!(answers, pair("2165", "534035653563227"));
+}
+
+#[test]
+fn day14_fixtures() {
+    // The first example has too many floating bits for the second part, and
+    // the second one is only meant for it.
+    assert_eq!(part1::<Day14>("files/day14/test1.txt"), "165");
+    assert_eq!(part2::<Day14>("files/day14/test2.txt"), "208");
+}
+
+#[test]
+fn day14_input() {
+    let answers = answers::<Day14>("files/day14/input.txt");
+    assert_eq!(answers, pair("12610010960049", "3608464522781"));
+}
+
+#[test]
+fn day15_fixtures() {
+    assert_eq!(part1::<Day15>("files/day15/test.txt"), "436");
+}
+
+#[test]
+fn day15_examples() {
+    let part1 = |numbers: &[usize]| day15::spoken_at(numbers, 2020).unwrap();
+    assert_eq!(part1(&[1, 3, 2]), 1);
+    assert_eq!(part1(&[3, 1, 2]), 1836);
+    assert_eq!(day15::spoken_at(&[0, 3, 6], 9).unwrap(), 4);
+}
+
+#[test]
+fn day15_input() {
+    let answers = answers::<Day15>("files/day15/input.txt");
+    assert_eq!(answers, pair("441", "10613991"));
+}
+
+#[test]
+fn day16_fixtures() {
+    assert_eq!(part1::<Day16>("files/day16/test1.txt"), "71");
+
+    let mut notes: Notes = parse::<Day16>("files/day16/test2.txt");
+    notes.discard_invalid_tickets();
+    assert_eq!(notes.find_out_labels_order(), ["row", "class", "seat"]);
+}
+
+#[test]
+fn day16_input() {
+    let answers = answers::<Day16>("files/day16/input.txt");
+    assert_eq!(answers, pair("18142", "1069784384303"));
+}
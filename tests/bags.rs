@@ -0,0 +1,116 @@
+use aoc_2020::day7::{BagsGraph, Day7, RuleError};
+use aoc_2020::Solution;
+use itertools::Itertools;
+
+/// Rules of `levels` bags, each one holding `qty` of the next one twice,
+/// through two different colours, the last level holds nothing.
+fn nested_bags(levels: usize, qty: usize) -> BagsGraph {
+    let mut rules = String::new();
+    for level in 0..levels {
+        rules += &format!(
+            "left l{0} bags contain {1} left l{2} bags, {1} right l{2} bags.\n\
+             right l{0} bags contain {1} left l{2} bags, {1} right l{2} bags.\n",
+            level,
+            qty,
+            level + 1
+        );
+    }
+
+    rules += &format!("left l{0} bags contain no other bags.\n", levels);
+    rules += &format!("right l{0} bags contain no other bags.\n", levels);
+    BagsGraph::parse(&rules)
+}
+
+#[test]
+fn day7_inside_count() {
+    // Each level doubles the bags, so 2 + 4 + ... + 2^levels.
+    let bags = nested_bags(40, 1);
+    assert_eq!(bags.inside_count("leftl0").unwrap(), (1 << 41) - 2);
+
+    let bags = nested_bags(5000, 1);
+    let error = bags.inside_count("leftl0").unwrap_err();
+    assert_eq!(error.to_string(), "Too many bags inside rightl4936 bags");
+
+    let bags = nested_bags(1, usize::MAX / 2);
+    assert_eq!(bags.inside_count("leftl0").unwrap(), usize::MAX - 1);
+    let bags = nested_bags(1, usize::MAX / 2 + 1);
+    assert!(bags.inside_count("leftl0").is_err());
+
+    let bags = BagsGraph::parse("bright red bags contain 2 dark blue bags.");
+    let error = bags.inside_count("brightred").unwrap_err();
+    assert_eq!(error.to_string(), "No rule for darkblue bags");
+}
+
+#[test]
+fn day7_deep_rules() {
+    // Far deeper than recursion could go.
+    let bags = nested_bags(50_000, 1);
+    assert_eq!(bags.validate(), Ok(()));
+    assert_eq!(bags.holds("leftl50000").count(), 100_000);
+    assert!(bags.inside_count("leftl0").is_err());
+}
+
+#[test]
+fn day7_validate() {
+    assert_eq!(nested_bags(5000, 1).validate(), Ok(()));
+
+    let rules = "shiny gold bags contain 1 dark red bag, 2 pale blue bags.
+dark red bags contain 1 light green bag.
+light green bags contain 3 shiny gold bags, 1 dark red bag.
+pale blue bags contain 1 faded black bag.
+dark red bags contain no other bags.
+dull teal bags contain 4 dull teal bags.
+dark red bags contain 1 light green bag.
+dark red bags hold 1 light green bag.
+";
+    let bags = BagsGraph::parse(rules);
+    let cycle = |bags: &[&str]| RuleError::Cycle(bags.iter().map(|bag| bag.to_string()).collect());
+    assert_eq!(
+        bags.validate(),
+        Err(vec![
+            RuleError::Malformed {
+                line: 8,
+                text: "dark red bags hold 1 light green bag.".to_owned(),
+            },
+            RuleError::Duplicate {
+                bag: "darkred".to_owned(),
+                line: 5,
+            },
+            RuleError::Duplicate {
+                bag: "darkred".to_owned(),
+                line: 7,
+            },
+            RuleError::Undefined {
+                bag: "fadedblack".to_owned(),
+                outer: "paleblue".to_owned(),
+            },
+            cycle(&["darkred", "lightgreen", "shinygold", "darkred"]),
+            cycle(&["darkred", "lightgreen", "darkred"]),
+            cycle(&["dullteal", "dullteal"]),
+        ])
+    );
+
+    // Nothing panics or recurses forever on rules that don't make sense.
+    let holders: Vec<_> = bags.holds("fadedblack").sorted().collect();
+    assert_eq!(holders, ["darkred", "lightgreen", "paleblue", "shinygold"]);
+
+    let holders: Vec<_> = bags.holds("dullteal").collect();
+    assert_eq!(holders, ["dullteal"]);
+    assert!(bags.inside_count("shinygold").is_err());
+    assert!(bags.inside_count("dullteal").is_err());
+
+    let error = Day7::parse(rules).unwrap_err().to_string();
+    assert!(
+        error.starts_with(
+            "Line 8: not a rule: \"dark red bags hold 1 light green bag.\"; \
+             Line 5: darkred bags already have a rule; "
+        ),
+        "{}",
+        error
+    );
+    assert!(
+        error.ends_with("; Bags contain themselves: dullteal -> dullteal"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,97 @@
+use aoc_2020::day5::{BoardingPass, Cabin, Day5, SeatMap};
+use aoc_2020::Solution;
+
+#[test]
+fn day5_boarding_pass() {
+    let examples = [
+        ("FBFBBFFRLR", 44, 5, 357),
+        ("BFFFBBFRRR", 70, 7, 567),
+        ("FFFBBBFRRR", 14, 7, 119),
+        ("BBFFBBFRLL", 102, 4, 820),
+    ];
+
+    for (code, row, column, id) in examples.iter().copied() {
+        let pass: BoardingPass = code.parse().unwrap();
+        assert_eq!((pass.row, pass.column, pass.id()), (row, column, id));
+        assert_eq!(pass.to_string(), code);
+
+        let cabin = Cabin::PUZZLE;
+        assert_eq!(BoardingPass::from_id(cabin, id).unwrap(), pass);
+        assert_eq!(BoardingPass::new(cabin, row, column).unwrap(), pass);
+    }
+
+    let error = |pass: &str| format!("{:#}", pass.parse::<BoardingPass>().unwrap_err());
+    assert_eq!(error("FBFBBFFRL"), "Expected 10 characters, got 9");
+    assert_eq!(error("FBFBBFFRLRR"), "Expected 10 characters, got 11");
+    assert_eq!(
+        error("FBFBBFLRLR"),
+        "Unexpected 'L' at position 7, expected F or B"
+    );
+    assert_eq!(
+        error("FBFBBFFRLé"),
+        "Unexpected 'é' at position 10, expected L or R"
+    );
+
+    assert!(BoardingPass::from_id(Cabin::PUZZLE, 1024).is_err());
+    assert!(BoardingPass::new(Cabin::PUZZLE, 0, 8).is_err());
+}
+
+#[test]
+fn day5_other_cabins() {
+    assert!(Cabin::new(100, 8).is_err());
+    assert!(Cabin::new(0, 8).is_err());
+    assert!(Cabin::new(1 << 40, 1 << 40).is_err());
+    assert!(Cabin::new(1 << 20, 1 << 5).is_err());
+    assert_eq!(
+        Cabin::new(1 << 20, 1 << 4).unwrap().seats(),
+        Cabin::MAX_SEATS
+    );
+
+    let cabin = Cabin::new(32, 4).unwrap();
+    let pass = BoardingPass::parse(cabin, "BFFFBRL").unwrap();
+    assert_eq!((pass.row, pass.column, pass.id()), (17, 2, 70));
+    assert_eq!(
+        BoardingPass::from_id(cabin, 70).unwrap().to_string(),
+        "BFFFBRL"
+    );
+    assert!(BoardingPass::parse(cabin, "FBFBBFFRLR").is_err());
+
+    let cabin = Cabin::new(1, 2).unwrap();
+    assert_eq!(BoardingPass::parse(cabin, "R").unwrap().id(), 1);
+    for id in 0..cabin.seats() {
+        let pass = BoardingPass::from_id(cabin, id).unwrap();
+        assert_eq!(BoardingPass::parse(cabin, &pass.to_string()).unwrap(), pass);
+    }
+}
+
+#[test]
+fn day5_seat_map() {
+    let cabin = Cabin::new(4, 2).unwrap();
+    let map = SeatMap::from_ids(cabin, &[1, 2, 2, 4, 6]).unwrap();
+    assert_eq!(map.passes(2), Some(2));
+    assert_eq!(map.passes(8), None);
+    assert_eq!(map.passes(usize::MAX), None);
+    assert_eq!(map.empty_runs(), [0..1, 3..4, 5..6, 7..8]);
+    assert_eq!(map.missing_seat(), Some(3));
+    assert_eq!(map.to_string(), "0 .#\n1 !.\n2 #.\n3 #.\n");
+
+    let ids: Vec<_> = map.empty_seats().map(|pass| pass.id()).collect();
+    assert_eq!(ids, [0, 3, 5, 7]);
+
+    let duplicates = map.duplicates();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!((duplicates[0].0.id(), duplicates[0].1), (2, 2));
+
+    assert!(SeatMap::from_ids(cabin, &[8]).is_err());
+    let mut map = SeatMap::new(cabin);
+    assert!(map
+        .add(BoardingPass::from_id(Cabin::PUZZLE, 1).unwrap())
+        .is_err());
+
+    // Nothing to be found without passes, or with only the seats at the ends free.
+    assert_eq!(map.missing_seat(), None);
+    assert_eq!(Day5::part2(&Vec::new()).ok(), None);
+    let map = SeatMap::from_ids(cabin, &[1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(map.empty_runs(), [0..1, 7..8]);
+    assert_eq!(map.missing_seat(), None);
+}
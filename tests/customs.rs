@@ -0,0 +1,51 @@
+use aoc_2020::day6::{question_stats, Answers, Day6, Group, QuestionStats};
+use aoc_2020::{read_input, Solution};
+
+#[test]
+fn day6_stats() {
+    let groups = Day6::parse(&read_input("files/day6/test.txt").unwrap()).unwrap();
+    let stats = question_stats(&groups);
+    assert_eq!(stats.len(), 26);
+    assert_eq!(
+        stats[0],
+        QuestionStats {
+            question: 'a',
+            people: 8,
+            anyone: 4,
+            everyone: 3,
+        }
+    );
+    assert_eq!(stats[25].people, 0);
+
+    let error = Day6::parse("ab\naB\n").unwrap_err();
+    assert_eq!(
+        format!("{:#}", error),
+        "Line 2: \"aB\": Unexpected 'B', questions go from a to z"
+    );
+}
+
+#[test]
+fn day6_answer_sets() {
+    let set = |answers: &str| answers.parse::<Answers>().unwrap();
+    assert_eq!(set("zca").to_string(), "acz");
+    assert_eq!(set("aa").len(), 1);
+    assert!(set("").is_empty());
+    assert_eq!(set("abc").union(set("cd")), set("abcd"));
+    assert_eq!(set("abc").intersection(set("cd")), set("c"));
+    assert_eq!(Answers::ALL.len(), 26);
+
+    let group = Group {
+        people: vec![set("abc"), set("ab"), set("bd"), set("b")],
+    };
+    assert_eq!(group.anyone(), set("abcd"));
+    assert_eq!(group.everyone(), set("b"));
+    assert_eq!(group.exactly(1), set("cd"));
+    assert_eq!(group.exactly(2), set("a"));
+    assert_eq!(group.exactly(0), Answers::NONE);
+    assert_eq!(group.majority(), set("b"));
+    assert_eq!(group.counts()[..4], [2, 4, 1, 1]);
+
+    let empty = Group { people: Vec::new() };
+    assert_eq!(empty.everyone(), Answers::NONE);
+    assert_eq!(empty.majority(), Answers::NONE);
+}
@@ -0,0 +1,71 @@
+use aoc_2020::day1::ExpenseReport;
+use itertools::Itertools;
+
+#[test]
+fn day1_find_sum() {
+    let report = ExpenseReport::new(vec![1721, 979, 366, 299, 675, 1456]);
+
+    let pair = report.find_sum(2020, 2).unwrap();
+    assert_eq!(pair.indices, [0, 3]);
+    assert_eq!(pair.entries, [1721, 299]);
+    assert_eq!(pair.product(), Some(514579));
+
+    let triplet = report.find_sum(2020, 3).unwrap();
+    assert_eq!(triplet.entries, [979, 366, 675]);
+
+    let four = report.find_sum(979 + 366 + 299 + 1456, 4).unwrap();
+    assert_eq!(four.indices, [1, 2, 3, 5]);
+    assert_eq!(report.find_sum(1, 4), None);
+    assert_eq!(report.find_sum(366, 1).unwrap().indices, [2]);
+    assert_eq!(report.find_sum(0, 7), None);
+}
+
+#[test]
+fn day1_find_sum_uses_distinct_entries() {
+    let report = ExpenseReport::new(vec![1010, 5, 1010]);
+    assert_eq!(report.find_sum(2020, 2).unwrap().indices, [0, 2]);
+
+    let report = ExpenseReport::new(vec![1010, 500, 10]);
+    assert_eq!(report.find_sum(2020, 2), None);
+    assert_eq!(report.find_sum(3030, 4), None);
+}
+
+#[test]
+fn day1_sums_every_combination() {
+    let entries = vec![5, -3, 5, 0, 10, 5, 8, -3, 2, 10];
+    let report = ExpenseReport::new(entries.clone());
+
+    for k in 0..=5 {
+        for target in -6..=30 {
+            let mut expected: Vec<Vec<usize>> = (0..entries.len())
+                .combinations(k)
+                .filter(|idx| idx.iter().map(|&i| entries[i] as i64).sum::<i64>() == target)
+                .collect();
+            expected.sort();
+
+            let mut found: Vec<_> = report.sums(target, k).map(|c| c.indices).collect();
+            found.sort();
+
+            assert_eq!(found, expected, "k = {}, target = {}", k, target);
+            let count = report.count_sums(target, k);
+            assert_eq!(
+                count,
+                expected.len() as u64,
+                "k = {}, target = {}",
+                k,
+                target
+            );
+        }
+    }
+}
+
+#[test]
+fn day1_sums_never_reuse_an_entry() {
+    let report = ExpenseReport::new(vec![1010, 3, 2017]);
+    assert_eq!(report.sums(2020, 2).count(), 1);
+    assert_eq!(report.count_sums(2020, 2), 1);
+
+    let report = ExpenseReport::new(vec![1010; 4]);
+    assert_eq!(report.sums(2020, 2).count(), 6);
+    assert_eq!(report.count_sums(2020, 2), 6);
+}
@@ -0,0 +1,60 @@
+use aoc_2020::grid::NEIGHBOURS_8;
+use aoc_2020::Grid;
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| Ok(c.to_digit(10).unwrap())).unwrap()
+}
+
+#[test]
+fn parse_keeps_shape() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+}
+
+#[test]
+fn parse_reports_bad_cells() {
+    let err = Grid::parse("..\n.#\n..", |c| match c {
+        '.' => Ok(()),
+        _ => anyhow::bail!("Bad char"),
+    })
+    .unwrap_err();
+    assert_eq!(format!("{:#}", err), "Line 2, column 2: '#': Bad char");
+
+    let err = Grid::parse("..\n...", Ok).unwrap_err();
+    assert_eq!(err.to_string(), "Line 2: expected 2 cells, got 3");
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let grid = digits("123\n456\n789");
+    let mut around: Vec<_> = grid.neighbours8(1, 1).copied().collect();
+    around.sort_unstable();
+    assert_eq!(around, [1, 2, 3, 4, 6, 7, 8, 9]);
+    assert_eq!(grid.neighbours8(0, 0).count(), 3);
+    assert_eq!(grid.neighbours4(0, 0).copied().collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(grid.neighbours(2, 2, &NEIGHBOURS_8).count(), 3);
+}
+
+#[test]
+fn wrap_repeats_horizontally() {
+    let grid = digits("12\n34").with_wrap_x(true);
+    assert_eq!(grid.get(-1, 0), Some(&2));
+    assert_eq!(grid.get(5, 1), Some(&4));
+    assert_eq!(grid.get(0, -1), None);
+    assert_eq!(grid.neighbours4(0, 0).count(), 3);
+}
+
+#[test]
+fn ray_stops_at_the_edge() {
+    let grid = digits("123\n456\n789");
+    let seen: Vec<_> = grid.ray(0, 0, (1, 1)).collect();
+    assert_eq!(seen, [((1, 1), &5), ((2, 2), &9)]);
+    assert_eq!(grid.ray(1, 1, (0, 0)).count(), 0);
+
+    let grid = grid.with_wrap_x(true);
+    let seen: Vec<_> = grid.ray(0, 0, (2, 1)).map(|(_, cell)| *cell).collect();
+    assert_eq!(seen, [6, 8]);
+}
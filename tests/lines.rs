@@ -0,0 +1,59 @@
+use std::env;
+use std::fs;
+
+use aoc_2020::day1::Day1;
+use aoc_2020::day12::Day12;
+use aoc_2020::day8::Day8;
+use aoc_2020::{parse_lines, read_input, Solution};
+
+#[test]
+fn parse_lines_reports_failing_line() {
+    let err = parse_lines::<u32>("1\n2\nthree\n4").unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "Line 3: \"three\": invalid digit found in string"
+    );
+}
+
+#[test]
+fn parse_lines_keeps_every_value() {
+    assert_eq!(parse_lines::<i32>("1\n-2\n3\n").unwrap(), [1, -2, 3]);
+}
+
+#[test]
+fn parse_lines_skips_blank_lines() {
+    assert_eq!(parse_lines::<i32>("1\n\n2\n  \n").unwrap(), [1, 2]);
+    let err = parse_lines::<i32>("1\n\nx\n").unwrap_err();
+    assert!(format!("{:#}", err).starts_with("Line 3: \"x\": "));
+
+    let report = Day1::parse("1721\n979\n366\n299\n675\n1456\n\n").unwrap();
+    assert_eq!(Day1::part1(&report).unwrap(), 514579);
+}
+
+#[test]
+fn malformed_lines_are_reported() {
+    let error = |input| format!("{:#}", Day8::parse(input).unwrap_err());
+    assert!(error("nop +0\n1 a b\n").starts_with("Line 2: \"1 a b\": Invalid value: \"a b\""));
+    assert_eq!(
+        error("nop +0\nbrk +1\n"),
+        "Line 2: \"brk +1\": Unknown instruction: \"brk\""
+    );
+    assert!(error("acc x\n").starts_with("Line 1: \"acc x\": Invalid value: \"x\""));
+
+    let error = |input| format!("{:#}", Day12::parse(input).unwrap_err());
+    assert_eq!(error("F10\nX3\n"), "Line 2: \"X3\": Unknown action: 'X'");
+}
+
+#[test]
+fn read_input_reports_invalid_utf8() {
+    let path = env::temp_dir().join("aoc-2020-invalid-utf8.txt");
+    fs::write(&path, b"1\n2\n\xff\xfe\n4\n").unwrap();
+
+    let err = read_input(&path).unwrap_err().to_string();
+    assert_eq!(
+        err,
+        format!("Failed to read {}:3: invalid UTF-8", path.display())
+    );
+
+    fs::remove_file(&path).unwrap();
+}
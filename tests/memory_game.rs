@@ -0,0 +1,56 @@
+use aoc_2020::day15::{self, Day15, Game};
+use aoc_2020::Solution;
+
+#[test]
+fn day15_game_keeps_playing() {
+    let mut game = Game::new(&[0, 3, 6]).unwrap();
+    let spoken: Vec<_> = (4..=10)
+        .map(|turn| game.play_until(turn).unwrap())
+        .collect();
+    assert_eq!(spoken, [0, 3, 3, 1, 0, 4, 0]);
+    assert_eq!(game.turn(), 10);
+
+    assert!(game.play_until(9).is_err());
+    assert_eq!(game.play_until(10).unwrap(), 0);
+    assert_eq!(game.play_until(30_000_000).unwrap(), 175594);
+}
+
+#[test]
+fn day15_game_starting_numbers() {
+    assert!(Game::new(&[]).is_err());
+    assert_eq!(Game::new(&[7]).unwrap().play_until(1).unwrap(), 7);
+    assert_eq!(Game::new(&[7]).unwrap().play_until(3).unwrap(), 0);
+    assert_eq!(Game::new(&[1000, 1000]).unwrap().play_until(3).unwrap(), 1);
+
+    // Turns of the starting numbers are answered with them, even later on.
+    let spoken: Vec<_> = (1..=3)
+        .map(|turn| day15::spoken_at(&[0, 3, 6], turn).unwrap())
+        .collect();
+    assert_eq!(spoken, [0, 3, 6]);
+    let mut game = Game::new(&[0, 3, 6]).unwrap();
+    game.play_until(10).unwrap();
+    assert_eq!(game.play_until(2).unwrap(), 3);
+
+    let error = day15::spoken_at(&[0, 3, 6], 0).unwrap_err();
+    assert_eq!(error.to_string(), "Turns start at 1");
+
+    // Numbers too big for the memory are rejected instead of allocated.
+    assert!(Game::new(&[usize::MAX, 1]).is_err());
+    assert!(Game::new(&[u32::MAX as usize, 1]).is_err());
+    assert!(Game::new(&[day15::MAX_MEMORY, 1]).is_err());
+    assert!(Game::new(&[day15::MAX_MEMORY - 1, 1]).is_ok());
+    assert!(Game::new(&[0])
+        .unwrap()
+        .play_until(day15::MAX_MEMORY + 1)
+        .is_err());
+    assert!(Day15::part1(&vec![usize::MAX, 1]).is_err());
+}
+
+#[test]
+fn day15_parse() {
+    assert_eq!(Day15::parse("0,3,6\n").unwrap(), [0, 3, 6]);
+    assert_eq!(Day15::parse(" 2, 1,3 ").unwrap(), [2, 1, 3]);
+    assert!(Day15::parse("").is_err());
+    assert!(Day15::parse("1,,2").is_err());
+    assert!(Day15::parse("1,-2").is_err());
+}
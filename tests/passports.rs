@@ -0,0 +1,260 @@
+use aoc_2020::day4::{
+    Batch, Day4, Diagnostic, Document, EyeColor, FieldError, Height, HexColor, Passport, Problem,
+    Rule, Schema,
+};
+use aoc_2020::{paragraphs, read_input, Solution};
+
+/// Xorshift generator, enough to build reproducible random inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+/// Bits passports are made of, plus some that shouldn't be there.
+const PIECES: &[&str] = &[
+    "byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid", ":", ":", "#", "cm", "in", "1", "19",
+    "2020", "0", "9", "a", "f", "z", "amb", "é", "🎄", " ", " ", "\t", "\n", "\n", "\r\n", "\n\n",
+    " \n \n",
+];
+
+/// Check that `input` can be read and every passport checked without
+/// panicking, and that the diagnostics point inside of the batch.
+fn check(input: &str) {
+    let batch = Batch::parse(input);
+    assert_eq!(batch.passports.len(), paragraphs(input).count());
+
+    let lines = input.lines().count();
+    for Diagnostic { line, passport, .. } in &batch.diagnostics {
+        assert!((1..=lines).contains(line), "{:?}", input);
+        assert!(
+            (1..=batch.passports.len()).contains(passport),
+            "{:?}",
+            input
+        );
+    }
+
+    let schema = Schema::puzzle();
+    for doc in &batch.passports {
+        let valid = schema.is_valid(doc);
+        assert_eq!(Passport::new(doc).is_ok(), valid, "{:?}", input);
+        assert!(!valid || schema.is_complete(doc));
+    }
+}
+
+#[test]
+fn random_batches_never_panic() {
+    let mut rng = Rng(0x2020_0004);
+    for _ in 0..3000 {
+        let len = rng.below(60);
+        let input: String = (0..len).map(|_| rng.pick(PIECES)).collect();
+        check(&input);
+    }
+}
+
+#[test]
+fn mutated_batches_never_panic() {
+    let input = read_input("files/day4/test3.txt").unwrap();
+    let mut rng = Rng(0x0004_2020);
+    for _ in 0..1000 {
+        let mut chars: Vec<char> = input.chars().collect();
+        for _ in 0..rng.below(8) + 1 {
+            let idx = rng.below(chars.len());
+            let piece: Vec<char> = rng.pick(PIECES).chars().collect();
+            match rng.below(3) {
+                0 => {
+                    chars.remove(idx);
+                }
+                1 => {
+                    chars.splice(idx..idx, piece);
+                }
+                _ => {
+                    chars.splice(idx..=idx, piece);
+                }
+            }
+
+            if chars.is_empty() {
+                break;
+            }
+        }
+
+        check(&chars.into_iter().collect::<String>());
+    }
+}
+
+#[test]
+fn batch_reports_problems() {
+    let input = "byr:1937\tiyr:2017\n  cid:147 hgt:183cm\n\n\n\
+                 hcl:#fffffd garbage\n:x byr:1 byr:2 ecl:\n";
+    let batch = Batch::parse(input);
+    assert_eq!(batch.passports.len(), 2);
+    assert_eq!(batch.passports[0].get("cid"), Some("147"));
+    assert_eq!(batch.passports[1].get("byr"), Some("2"));
+    assert_eq!(batch.passports[1].get("ecl"), Some(""));
+
+    let diagnostic = |line, problem| Diagnostic {
+        line,
+        passport: 2,
+        problem,
+    };
+    assert_eq!(
+        batch.diagnostics,
+        [
+            diagnostic(5, Problem::Garbage("garbage".to_owned())),
+            diagnostic(6, Problem::Garbage(":x".to_owned())),
+            diagnostic(6, Problem::Duplicate("byr".to_owned())),
+        ]
+    );
+    assert_eq!(
+        batch.diagnostics[0].to_string(),
+        "Line 5, passport 2: not a key:val pair: \"garbage\""
+    );
+}
+
+#[test]
+fn multibyte_values_are_invalid() {
+    let input = "byr:1937 iyr:2017 eyr:2020 hgt:é hcl:#ffffé ecl:amb pid:12345678é";
+    let batch = Batch::parse(input);
+    let errors = Passport::new(&batch.passports[0]).unwrap_err();
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(!Schema::puzzle().is_valid(&batch.passports[0]));
+}
+
+#[test]
+fn day4_puzzle_rules() {
+    let schema = Schema::puzzle();
+    let check = |key: &str, val: &str| schema.field(key).unwrap().rule.matches(val);
+
+    assert!(check("byr", "2002"));
+    assert!(!check("byr", "2003"));
+    assert!(check("hgt", "60in"));
+    assert!(check("hgt", "190cm"));
+    assert!(!check("hgt", "190in"));
+    assert!(!check("hgt", "190"));
+    assert!(!check("hgt", "n"));
+    assert!(!check("hgt", "1é"));
+    assert!(check("hcl", "#123abc"));
+    assert!(!check("hcl", "#123abz"));
+    assert!(!check("hcl", "123abc"));
+    assert!(check("ecl", "brn"));
+    assert!(!check("ecl", "wat"));
+    assert!(check("pid", "000000001"));
+    assert!(!check("pid", "0123456789"));
+    assert!(!schema.field("cid").unwrap().required);
+}
+
+#[test]
+fn day4_custom_schema() {
+    let schema: Schema = "# Library card\n\
+                          \n\
+                          num required regex [A-Z]{2}[0-9]{4}\n\
+                          age required range 12-120\n\
+                          len optional units 1-12m 1-52w\n\
+                          tier optional enum gold silver"
+        .parse()
+        .unwrap();
+
+    let doc = |text: &str| Document::parse(text);
+    assert!(schema.is_valid(&doc("num:AB1234 age:30")));
+    assert!(schema.is_valid(&doc("num:AB1234\nage:30 len:40w tier:gold")));
+    assert!(schema.is_complete(&doc("num:A age:3")));
+    assert!(!schema.is_valid(&doc("num:A age:3")));
+    assert!(!schema.is_complete(&doc("num:AB1234")));
+    assert!(!schema.is_complete(&doc("num:AB1234 age:30 pet:cat")));
+    assert!(!schema.is_valid(&doc("num:AB1234 age:30 len:13m")));
+
+    let error = |schema: &str| format!("{:#}", schema.parse::<Schema>().unwrap_err());
+    assert_eq!(
+        error("a required range 3-1"),
+        "Line 1: \"a required range 3-1\": Invalid range: 3-1"
+    );
+    assert_eq!(
+        error("a maybe any"),
+        "Line 1: \"a maybe any\": Expected required or optional"
+    );
+    assert_eq!(
+        error("a required units 1-2"),
+        "Line 1: \"a required units 1-2\": Missing unit after \"1-2\""
+    );
+    assert_eq!(
+        error("a required any\na optional any"),
+        "Line 2: field a is repeated"
+    );
+    assert!("a required sometimes".parse::<Rule>().is_err());
+    assert!(Schema::load("files/day4/schema.txt").is_ok());
+}
+
+#[test]
+fn day4_passport() {
+    let doc =
+        Document::parse("hcl:#623a2f pid:087499704 hgt:74in\necl:grn iyr:2012 eyr:2030 byr:1980");
+    let passport = Passport::new(&doc).unwrap();
+    assert_eq!(passport.birth_year, 1980);
+    assert_eq!(passport.height, Height::In(74));
+    assert_eq!(passport.hair_color, HexColor(0x623a2f));
+    assert_eq!(passport.eye_color, EyeColor::Green);
+    assert_eq!(passport.passport_id.as_str(), "087499704");
+    assert_eq!(passport.country_id, None);
+
+    let doc = Document::parse("hcl:#623a2f hgt:74 ecl:grn iyr:2012 eyr:2030 byr:1980 pet:cat");
+    let errors = Passport::new(&doc).unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            FieldError::Invalid {
+                key: "hgt",
+                val: "74".to_owned(),
+                reason: "Missing unit, expected cm or in".to_owned(),
+            },
+            FieldError::Missing("pid"),
+            FieldError::Unknown("pet".to_owned()),
+        ]
+    );
+    assert_eq!(errors[1].to_string(), "pid: missing");
+
+    // The limits are the ones of the puzzle schema.
+    let doc =
+        Document::parse("hcl:#623a2f pid:087499704 hgt:77in ecl:red iyr:2012 eyr:2030 byr:2003");
+    let errors: Vec<_> = Passport::new(&doc)
+        .unwrap_err()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            "byr: \"2003\": Must be within 1920 and 2002",
+            "hgt: \"77in\": Must be within 59 and 76",
+            "ecl: \"red\": Expected one of amb, blu, brn, gry, grn, hzl or oth",
+        ]
+    );
+}
+
+#[test]
+fn day4_passports_match_schema() {
+    let batch = Day4::parse(&read_input("files/day4/input.txt").unwrap()).unwrap();
+    assert_eq!(batch.diagnostics, []);
+
+    let schema = Schema::puzzle();
+    for doc in &batch.passports {
+        assert_eq!(
+            Passport::new(doc).is_ok(),
+            schema.is_valid(doc),
+            "{:?}",
+            doc
+        );
+    }
+}
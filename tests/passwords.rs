@@ -0,0 +1,146 @@
+use aoc_2020::day2::{self, Audit, Day2, ExactlyOne, PasswordEntry, PasswordPolicy, Policy};
+use aoc_2020::Solution;
+
+#[test]
+fn day2_parse_entries() {
+    let entry: PasswordEntry = "1-3 a: abcde".parse().unwrap();
+    let policy = PasswordPolicy {
+        first: 1,
+        second: 3,
+        letter: 'a',
+    };
+    assert_eq!(entry.policy, policy);
+    assert_eq!(entry.password, "abcde");
+
+    let error = |line: &str| format!("{:#}", line.parse::<PasswordEntry>().unwrap_err());
+    assert_eq!(error("3-1 a: abc"), "Invalid range: 3-1");
+    assert_eq!(error("0-2 a: abc"), "Positions start at 1, got 0-2");
+    assert_eq!(error("1-2 ab: abc"), "Expected a single letter, got \"ab\"");
+    assert_eq!(error("1-2 a:"), "Missing password");
+    assert_eq!(error("1-2 a abc"), "Missing password");
+    assert_eq!(error("1-2: abc"), "Missing policy letter");
+    assert_eq!(error("1 a: abc"), "Expected a range, got \"1\"");
+    assert!(error("1-x a: abc").starts_with("Invalid number: \"x\""));
+}
+
+#[test]
+fn day2_parse_reports_line() {
+    let err = Day2::parse("1-3 a: abcde\n1-3 b cdefg\n").unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "Line 2: \"1-3 b cdefg\": Missing password"
+    );
+}
+
+#[test]
+fn day2_policies() {
+    let entries = Day2::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+    let verdicts = |spec: &str| {
+        let policy = day2::parse_policy(spec).unwrap();
+        let verdicts: Vec<_> = entries.iter().map(|e| policy.is_valid(e)).collect();
+        (policy.to_string(), verdicts)
+    };
+
+    let check = |spec: &str, expected: [bool; 3]| {
+        assert_eq!(
+            verdicts(spec),
+            (spec.to_owned(), expected.to_vec()),
+            "{}",
+            spec
+        );
+    };
+
+    check("range", [true, false, true]);
+    check("one-of", [true, false, false]);
+    check("at-least:1", [true, false, true]);
+    check("at-least:2", [false, false, true]);
+    check("none-of", [false, true, false]);
+    check("regex:^c+$", [false, false, true]);
+    check("range and none-of", [false, false, false]);
+    check("one-of or regex:^c+$", [true, false, true]);
+    check("none-of or range and at-least:2", [false, true, true]);
+
+    // Patterns holding the words that combine policies go between quotes,
+    // and are displayed that way too.
+    check(r#"regex:"^(cdefg or abcde)$""#, [false, false, false]);
+    check(r#"regex:"^(cdefg| or )$" or range"#, [true, true, true]);
+    check(r#"regex:"^\"?c+$" and range"#, [false, false, true]);
+    let policy = day2::parse_policy(r#"regex:"a and b" or one-of"#).unwrap();
+    assert_eq!(policy.to_string(), r#"regex:"a and b" or one-of"#);
+    let policy = day2::parse_policy(r#"regex:"^\"$""#).unwrap();
+    assert_eq!(policy.to_string(), r#"regex:"^\"$""#);
+
+    assert!(day2::parse_policy("some-of").is_err());
+    assert!(day2::parse_policy("at-least:x").is_err());
+    assert!(day2::parse_policy("regex:(").is_err());
+    assert!(day2::parse_policy(r#"regex:"a and b"#).is_err());
+    assert!(day2::parse_policy(r#"regex:"a"b""#).is_err());
+    assert!(day2::parse_policy(r#"regex:a"b"#).is_err());
+}
+
+#[test]
+fn day2_violations() {
+    let entries = Day2::parse("1-3 a: aaaaa\n1-3 b: bdbx\n1-9 c: cx\n").unwrap();
+    let reasons = |spec: &str| {
+        let policy = day2::parse_policy(spec).unwrap();
+        let reasons: Vec<_> = entries.iter().map(|e| policy.violation(e)).collect();
+        reasons
+    };
+    let reason = |reason: &str| Some(reason.to_owned());
+
+    let range = reasons("range");
+    assert_eq!(range[0], reason("letter 'a' appears 5 times, allowed 1-3"));
+    assert_eq!(range[1], None);
+
+    let one_of = reasons("one-of");
+    assert_eq!(one_of[1], reason("positions 1 and 3 both contain 'b'"));
+    assert_eq!(
+        one_of[2],
+        reason("position 9 is past the end of the password")
+    );
+
+    // Equal positions count twice, as they always did.
+    let same = Day2::parse("2-2 a: bac\n2-2 a: abc\n").unwrap();
+    assert_eq!(Day2::part2(&same).unwrap(), 0);
+    assert_eq!(
+        ExactlyOne.violation(&same[0]),
+        reason("positions 2 and 2 both contain 'a'")
+    );
+    assert_eq!(
+        ExactlyOne.violation(&same[1]),
+        reason("no letter 'a' in positions 2 and 2")
+    );
+
+    let none_of = reasons("none-of");
+    assert_eq!(none_of[0], reason("positions 1 and 3 both contain 'a'"));
+    assert_eq!(none_of[2], reason("position 1 contains 'c'"));
+
+    let either = reasons("range or regex:x$");
+    assert_eq!(
+        either[0],
+        reason("letter 'a' appears 5 times, allowed 1-3; password doesn't match /x$/")
+    );
+    assert_eq!(either[1], None);
+}
+
+#[test]
+fn day2_audit() {
+    let entries = Day2::parse("1-3 a: abcde\n2-9 c: \"c\"\n").unwrap();
+    let policies = vec![day2::parse_policy("range").unwrap()];
+    let audit = Audit::new(&entries, &policies);
+
+    assert_eq!(
+        audit.to_string(),
+        "line  policy  password  range    reason\n\
+         1     1-3 a   abcde     valid\n\
+         2     2-9 c   \"c\"       invalid  range: letter 'c' appears 1 times, allowed 2-9\n"
+    );
+
+    let json: Vec<_> = audit.json_lines().collect();
+    assert_eq!(
+        json[1],
+        "{\"line\":2,\"policy\":\"2-9 c\",\"password\":\"\\\"c\\\"\",\"verdicts\":[\
+         {\"policy\":\"range\",\"valid\":false,\
+         \"reason\":\"letter 'c' appears 1 times, allowed 2-9\"}]}"
+    );
+}
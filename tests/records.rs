@@ -0,0 +1,50 @@
+use aoc_2020::{groups, numbered_groups, paragraphs};
+
+#[test]
+fn last_group_without_trailing_blank_line() {
+    let input = "a\nb\n\nc";
+    assert_eq!(
+        groups(input).collect::<Vec<_>>(),
+        [vec!["a", "b"], vec!["c"]]
+    );
+}
+
+#[test]
+fn repeated_and_surrounding_blank_lines() {
+    let input = "\n\na\n \n\n\t\nb\nc\n\n\n";
+    assert_eq!(
+        groups(input).collect::<Vec<_>>(),
+        [vec!["a"], vec!["b", "c"]]
+    );
+}
+
+#[test]
+fn crlf_line_terminators() {
+    let input = "a\r\nb\r\n\r\nc\r\n";
+    assert_eq!(
+        groups(input).collect::<Vec<_>>(),
+        [vec!["a", "b"], vec!["c"]]
+    );
+    assert_eq!(paragraphs(input).collect::<Vec<_>>(), ["a\r\nb", "c"]);
+}
+
+#[test]
+fn paragraphs_keep_inner_line_terminators() {
+    let input = "a b\nc\n\nd\ne";
+    assert_eq!(paragraphs(input).collect::<Vec<_>>(), ["a b\nc", "d\ne"]);
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(groups("").count(), 0);
+    assert_eq!(paragraphs("\n\n").count(), 0);
+}
+
+#[test]
+fn numbered_groups_count_every_line() {
+    let input = "\r\na\r\n\r\n\r\nb\r\nc";
+    assert_eq!(
+        numbered_groups(input).collect::<Vec<_>>(),
+        [vec![(2, "a")], vec![(5, "b"), (6, "c")]]
+    );
+}
@@ -0,0 +1,67 @@
+use aoc_2020::day3::{Day3, Goal, Map, Slope, PUZZLE_SLOPES};
+use aoc_2020::{read_input, Solution};
+
+/// Map of the example in the puzzle.
+fn example() -> Map {
+    Day3::parse(&read_input("files/day3/test.txt").unwrap()).unwrap()
+}
+
+#[test]
+fn day3_slopes() {
+    let map = example();
+    let (trees, product) = map.survey(&PUZZLE_SLOPES);
+    assert_eq!(trees, [2, 7, 3, 4, 2]);
+    assert_eq!(product, Some(336));
+
+    let path: Vec<_> = map.path(Slope::new(3, 1)).take(4).collect();
+    assert_eq!(
+        path,
+        [((3, 1), '.'), ((6, 2), '#'), ((9, 3), '.'), ((12, 4), '#')]
+    );
+    assert_eq!(map.path(Slope::new(1, 2)).last(), Some(((5, 10), '.')));
+    assert_eq!(map.path(Slope::new(1, 0)).count(), 0);
+
+    let fewest = map.best_slope(1..=7, 1..=2, Goal::FewestTrees);
+    let most = map.best_slope(1..=7, 0..=2, Goal::MostTrees);
+    assert_eq!(fewest.map(|(_, trees)| trees), Some(0));
+    assert_eq!(most, Some((Slope::new(3, 1), 7)));
+    assert_eq!(map.best_slope(1..=7, 0..=0, Goal::MostTrees), None);
+
+    assert_eq!("3,1".parse::<Slope>().unwrap(), Slope::new(3, 1));
+    assert!("3,0".parse::<Slope>().is_err());
+    assert!("3".parse::<Slope>().is_err());
+}
+
+#[test]
+fn day3_render() {
+    let map = example();
+    let drawn = map
+        .render()
+        .slope(Slope::new(3, 1))
+        .slope(Slope::new(1, 2))
+        .columns(0..14)
+        .rows(0..5)
+        .to_string();
+
+    assert_eq!(
+        drawn,
+        "..##.........#\n\
+         #..O#...#..#..\n\
+         .X....X..#..#.\n\
+         ..#.#...#O#..#\n\
+         .#O..##..#..X.\n"
+    );
+
+    // Without a window only the map's own width is drawn, the path going
+    // past it is only shown by asking for more columns.
+    let drawn = map.render().slope(Slope::new(3, 1)).to_string();
+    assert!(drawn.lines().all(|line| line.len() == map.width()));
+    assert_eq!(drawn.lines().last().unwrap(), ".#..#...#.#");
+    let drawn = map.render().slope(Slope::new(3, 1)).columns(0..31);
+    let last = drawn.to_string().lines().last().unwrap().to_owned();
+    assert_eq!(last, ".#..#...#.#.#..#...#.#.#..#...X");
+
+    let drawn = map.render().slope(Slope::new(1, 1)).colour(true);
+    let drawn = drawn.rows(1..2).columns(0..3).to_string();
+    assert_eq!(drawn, "#\x1b[1;31mO\x1b[0m.\n");
+}